@@ -0,0 +1,156 @@
+use std::{env, fs, hint::black_box, path::Path};
+
+use anyhow::Result;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+use cli::{
+    Answer, DEFAULT_INPUT_DIR, Example, InputStore, Part, Solution, example_dir, read_input,
+};
+
+/// An input to benchmark a day against, with the tunable parameters that it needs, and the parts
+/// that it can be solved for.
+struct BenchInput {
+    name: String,
+    input: String,
+    params: Vec<(String, String)>,
+    parts: Vec<Part>,
+}
+
+/// Gets every example of a day, and the real input if it is in the input store.
+///
+/// Examples are only solved for the parts with a known answer, as some of them, such as day 17's
+/// part 1 example, do not work for the other part.
+fn bench_inputs(day: u8) -> Result<Vec<BenchInput>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(example_dir(day))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt")
+            && let Some(name) = path.file_stem().and_then(|name| name.to_str())
+            && !matches!(name, "answers" | "params")
+        {
+            names.push(String::from(name));
+        }
+    }
+    names.sort();
+
+    let mut inputs = names
+        .into_iter()
+        .map(|name| {
+            let example = Example::load(day, &name)?;
+
+            let parts = [Part::Part1, Part::Part2]
+                .into_iter()
+                .filter(|&part| example.answer(part).is_some())
+                .collect();
+
+            Ok(BenchInput {
+                name,
+                input: example.input,
+                params: example.params,
+                parts,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let store =
+        InputStore::new(env::var("AOC_INPUT_DIR").unwrap_or(String::from(DEFAULT_INPUT_DIR)));
+    let path = store.path(day);
+    if path.exists() {
+        inputs.push(BenchInput {
+            name: String::from("input"),
+            input: read_input(&path)?,
+            params: Vec::new(),
+            parts: vec![Part::Part1, Part::Part2],
+        });
+    }
+
+    Ok(inputs)
+}
+
+fn solve<S: Solution>(solver: &S, part: Part, input: &S::Input<'_>) -> Result<Answer> {
+    match part {
+        Part::Part1 => solver.part_1(input),
+        Part::Part2 => solver.part_2(input),
+    }
+}
+
+/// Benchmarks the parse phase, and then each part, of a day as separate groups.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, new_solver: impl Fn() -> S) {
+    let inputs = bench_inputs(day)
+        .and_then(|inputs| {
+            inputs
+                .into_iter()
+                .map(|input| {
+                    let mut solver = new_solver();
+                    for (key, value) in &input.params {
+                        solver.set_param(key, value)?;
+                    }
+
+                    Ok((input, solver))
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .unwrap_or_else(|error| panic!("Cannot load inputs for day {day}: {error:#}"));
+
+    let mut group = c.benchmark_group(format!("day-{day}/parse"));
+    for (input, solver) in &inputs {
+        group.bench_function(&input.name, |b| {
+            b.iter(|| solver.parse(black_box(&input.input)))
+        });
+    }
+    group.finish();
+
+    for part in [Part::Part1, Part::Part2] {
+        let mut group = c.benchmark_group(format!("day-{day}/part-{part}"));
+        group.sample_size(10);
+
+        for (input, solver) in inputs
+            .iter()
+            .filter(|(input, _)| input.parts.contains(&part))
+        {
+            let parsed = solver.parse(&input.input).unwrap_or_else(|error| {
+                panic!("Cannot parse {} for day {day}: {error:#}", input.name)
+            });
+
+            group.bench_function(&input.name, |b| {
+                b.iter(|| solve(solver, part, black_box(&parsed)))
+            });
+        }
+        group.finish();
+    }
+}
+
+fn days(c: &mut Criterion) {
+    // The examples and the input store are relative to the workspace root.
+    env::set_current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+        .expect("Cannot change to the workspace root");
+
+    bench_day(c, 1, || day_1::Solver);
+    bench_day(c, 2, || day_2::Solver);
+    bench_day(c, 3, || day_3::Solver);
+    bench_day(c, 4, || day_4::Solver);
+    bench_day(c, 5, || day_5::Solver);
+    bench_day(c, 6, || day_6::Solver);
+    bench_day(c, 7, || day_7::Solver);
+    bench_day(c, 8, || day_8::Solver);
+    bench_day(c, 9, || day_9::Solver);
+    bench_day(c, 10, || day_10::Solver);
+    bench_day(c, 11, day_11::Solver::default);
+    bench_day(c, 12, || day_12::Solver);
+    bench_day(c, 13, || day_13::Solver);
+    bench_day(c, 14, day_14::Solver::default);
+    bench_day(c, 15, || day_15::Solver);
+    bench_day(c, 16, || day_16::Solver);
+    bench_day(c, 17, || day_17::Solver);
+    bench_day(c, 18, day_18::Solver::default);
+    bench_day(c, 19, || day_19::Solver);
+    bench_day(c, 20, day_20::Solver::default);
+    bench_day(c, 21, day_21::Solver::default);
+    bench_day(c, 22, day_22::Solver::default);
+    bench_day(c, 23, || day_23::Solver);
+    bench_day(c, 24, || day_24::Solver);
+    bench_day(c, 25, || day_25::Solver);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use cli::{Bench, InputStore, Part, Registry, Stats, read_input};
+
+/// The timings for one part of one day.
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+
+    #[serde(flatten)]
+    bench: Bench,
+}
+
+/// Benchmarks the given day and part, or all of them when not given, and prints the timings.
+///
+/// Days without an input file are skipped, unless the day is given.
+pub fn bench(
+    registry: &Registry,
+    store: &InputStore,
+    day: Option<u8>,
+    part: Option<u8>,
+    iterations: usize,
+    json: bool,
+) -> Result<()> {
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => vec![Part::Part1, Part::Part2],
+    };
+
+    let days = match day {
+        Some(day) => vec![(day, registry.get(day)?)],
+        None => registry
+            .days()
+            .filter(|(day, _)| store.path(*day).exists())
+            .collect(),
+    };
+
+    let mut records = Vec::new();
+    for (day, puzzle) in days {
+        let input = read_input(&store.path(day))?;
+
+        for part in &parts {
+            let bench = puzzle.bench(*part, &input, iterations)?;
+            if !json {
+                print_record(day, *part, &bench);
+            }
+
+            records.push(Record {
+                day,
+                part: u8::from(*part),
+                bench,
+            });
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&records)?);
+    }
+
+    Ok(())
+}
+
+fn print_record(day: u8, part: Part, bench: &Bench) {
+    let format = |stats: &Stats| {
+        let [min, median, mean, stddev] = [stats.min, stats.median, stats.mean, stats.stddev]
+            .map(|d: Duration| format!("{d:.2?}"));
+
+        format!("min {min}, median {median}, mean {mean}, stddev {stddev}")
+    };
+
+    println!("day {day} part {part}");
+    println!("  parse: {}", format(&bench.parse));
+    println!("  solve: {}", format(&bench.solve));
+}
@@ -0,0 +1,61 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use cli::{Client, InputStore, Integrity, Registry};
+
+/// Copies an input file into the store, and prints its checksum.
+pub fn add(store: &InputStore, day: u8, file: &Path) -> Result<()> {
+    let checksum = store.add(day, file)?;
+    println!("day {day}: {checksum}");
+
+    Ok(())
+}
+
+/// Prints whether each registered day has an input, and whether it matches the manifest.
+///
+/// Inputs in the store for days that are not registered are listed too.
+pub fn list(registry: &Registry, store: &InputStore) -> Result<()> {
+    for (day, _) in registry.days() {
+        let status = match store.integrity(day)? {
+            Integrity::Missing => "missing",
+            Integrity::Unrecorded => "present, not in manifest",
+            Integrity::Unchanged => "present",
+            Integrity::Modified => "present, modified since added",
+        };
+
+        println!("day {day}: {status}");
+    }
+
+    for day in store.days()? {
+        if registry.get(day).is_err() {
+            println!("day {day}: present, no such day");
+        }
+    }
+
+    Ok(())
+}
+
+/// Downloads the inputs for the days into the store, skipping those already present unless forced.
+pub fn fetch(
+    registry: &Registry,
+    store: &InputStore,
+    client: &Client,
+    days: &[u8],
+    force: bool,
+) -> Result<()> {
+    for &day in days {
+        // Check the day before sending any request.
+        registry.get(day)?;
+
+        if !force && store.path(day).exists() {
+            println!("day {day}: already present");
+            continue;
+        }
+
+        let checksum = store.insert(day, &client.fetch_input(day)?)?;
+        println!("day {day}: {checksum}");
+    }
+
+    Ok(())
+}
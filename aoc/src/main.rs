@@ -0,0 +1,378 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anyhow::{Result, anyhow};
+use clap::{Parser, Subcommand};
+
+use cli::{
+    Answer, Attempt, Client, DEFAULT_BASE_URL, DEFAULT_INPUT_DIR, Example, History, InputStore,
+    KnownAnswers, Outcome, Part, Puzzle, Verdict, parse_param, read_input, report, report_error,
+    verify,
+};
+use registry::registry;
+
+mod bench;
+mod input;
+mod registry;
+mod summary;
+
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+
+    /// The root directory of the input store.
+    #[arg(long, global = true, env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+
+    /// The base URL of the puzzle server.
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// The session token for the puzzle server.
+    #[arg(long, global = true, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves one part of a day's puzzle, or every part of every day with --all.
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+
+        #[arg(required_unless_present = "all")]
+        part: Option<u8>,
+
+        #[arg(short = 'i', long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+
+        /// Solves the named example from examples/day-<DAY>/, and shows the expected answer.
+        #[arg(short = 'e', long, conflicts_with_all = ["input", "all"])]
+        example: Option<String>,
+
+        /// Sets a tunable parameter of the puzzle.
+        #[arg(short = 'p', long = "param", value_name = "KEY=VALUE", value_parser = parse_param, conflicts_with = "all")]
+        params: Vec<(String, String)>,
+
+        /// Solves every part of every day, and prints a summary table.
+        #[arg(long, conflicts_with_all = ["day", "part"])]
+        all: bool,
+
+        /// Solves the days in parallel, with --all.
+        #[arg(long, conflicts_with_all = ["day", "part"])]
+        parallel: bool,
+    },
+
+    /// Solves every day's puzzle and checks the answers against the known answers.
+    Verify {
+        #[arg(short = 'a', long, default_value = "answers.txt")]
+        answers: PathBuf,
+    },
+
+    /// Times the parse and solve phases of a day's puzzle, or of every day with an input file.
+    Bench {
+        day: Option<u8>,
+        part: Option<u8>,
+
+        /// The number of times to run each phase.
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Prints the timings as JSON, in nanoseconds.
+        #[arg(long)]
+        json: bool,
+
+        /// Sets a tunable parameter of the puzzle.
+        #[arg(short = 'p', long = "param", value_name = "KEY=VALUE", value_parser = parse_param, requires = "day")]
+        params: Vec<(String, String)>,
+    },
+
+    /// Solves one part of a day's puzzle, and submits the answer to the puzzle server.
+    ///
+    /// Every attempt is recorded in the history file, and answers known to be wrong are refused.
+    Submit {
+        day: u8,
+        part: u8,
+
+        #[arg(short = 'i', long)]
+        input: Option<PathBuf>,
+
+        /// Submits this answer instead of solving the puzzle.
+        #[arg(short = 'a', long, conflicts_with_all = ["input", "params"])]
+        answer: Option<String>,
+
+        /// The history file [default: <INPUT_DIR>/submissions.txt]
+        #[arg(long)]
+        history: Option<PathBuf>,
+
+        /// Sets a tunable parameter of the puzzle.
+        #[arg(short = 'p', long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+    },
+
+    /// Lists the tunable parameters of a day's puzzle, with their default values.
+    Params { day: u8 },
+
+    /// Manages the input store.
+    Input {
+        #[command(subcommand)]
+        command: InputCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputCommand {
+    /// Copies an input file into the store, and records its checksum in the manifest.
+    Add { day: u8, file: PathBuf },
+
+    /// Lists whether each day has an input, and whether it still matches its checksum.
+    List,
+
+    /// Downloads the inputs for the days from the puzzle server into the store.
+    Fetch {
+        #[arg(required = true)]
+        days: Vec<u8>,
+
+        /// Downloads the input even if the store already has it.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let store = InputStore::new(args.input_dir);
+
+    match args.command {
+        Command::Run {
+            all: true,
+            parallel,
+            ..
+        } => summarise(&store, parallel),
+        Command::Run {
+            day: Some(day),
+            part: Some(part),
+            example: Some(example),
+            params,
+            ..
+        } => report_error(run_example(day, part, &example, &params)),
+        Command::Run {
+            day: Some(day),
+            part: Some(part),
+            input,
+            params,
+            ..
+        } => report(run(&store, day, part, input, &params)),
+        Command::Run { .. } => unreachable!("The day and part are required without --all"),
+        Command::Verify { answers } => check(&store, &answers),
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            json,
+            params,
+        } => report_error(bench(&store, day, part, iterations as usize, json, &params)),
+        Command::Submit {
+            day,
+            part,
+            input,
+            answer,
+            history,
+            params,
+        } => {
+            let history = history.unwrap_or(store.root().join("submissions.txt"));
+            let answer = match answer {
+                Some(answer) => Ok(Answer::from(answer)),
+                None => run(&store, day, part, input, &params),
+            };
+
+            report_error(answer.and_then(|answer| {
+                submit(&args.base_url, args.session, &history, day, part, answer)
+            }))
+        }
+        Command::Params { day } => report_error(list_params(day)),
+        Command::Input {
+            command: InputCommand::Add { day, file },
+        } => report_error(input::add(&store, day, &file)),
+        Command::Input {
+            command: InputCommand::List,
+        } => report_error(input::list(&registry(), &store)),
+        Command::Input {
+            command: InputCommand::Fetch { days, force },
+        } => report_error(fetch(&store, &args.base_url, args.session, &days, force)),
+    }
+}
+
+fn run(
+    store: &InputStore,
+    day: u8,
+    part: u8,
+    input: Option<PathBuf>,
+    params: &[(String, String)],
+) -> Result<Answer> {
+    let mut registry = registry();
+    let part = Part::try_from(part)?;
+
+    // Check the day before looking for its input.
+    let puzzle = registry.get_mut(day)?;
+    set_params(puzzle, params)?;
+
+    let path = input.unwrap_or(store.path(day));
+    let input = read_input(&path)?;
+
+    puzzle.solve(part, &input)
+}
+
+fn run_example(day: u8, part: u8, name: &str, params: &[(String, String)]) -> Result<()> {
+    let mut registry = registry();
+    let part = Part::try_from(part)?;
+    let puzzle = registry.get_mut(day)?;
+
+    // Parameters from the command line take precedence over the example's own.
+    let example = Example::load(day, name)?;
+    set_params(puzzle, &example.params)?;
+    set_params(puzzle, params)?;
+    let answer = puzzle.solve(part, &example.input)?.to_string();
+
+    println!("expected: {}", example.answer(part).unwrap_or("unknown"));
+    println!("actual: {answer}");
+
+    match example.answer(part) {
+        Some(expected) if expected != answer => Err(anyhow!("Example answer mismatch")),
+        _ => Ok(()),
+    }
+}
+
+fn bench(
+    store: &InputStore,
+    day: Option<u8>,
+    part: Option<u8>,
+    iterations: usize,
+    json: bool,
+    params: &[(String, String)],
+) -> Result<()> {
+    let mut registry = registry();
+    if let Some(day) = day {
+        set_params(registry.get_mut(day)?, params)?;
+    }
+
+    bench::bench(&registry, store, day, part, iterations, json)
+}
+
+fn fetch(
+    store: &InputStore,
+    base_url: &str,
+    session: Option<String>,
+    days: &[u8],
+    force: bool,
+) -> Result<()> {
+    let session = session.ok_or(anyhow!(
+        "Cannot fetch input without a session token, set --session or AOC_SESSION"
+    ))?;
+
+    input::fetch(
+        &registry(),
+        store,
+        &Client::new(base_url, &session),
+        days,
+        force,
+    )
+}
+
+fn submit(
+    base_url: &str,
+    session: Option<String>,
+    history: &Path,
+    day: u8,
+    part: u8,
+    answer: Answer,
+) -> Result<()> {
+    let part = Part::try_from(part)?;
+    if answer == Answer::NotApplicable {
+        return Err(anyhow!(
+            "Cannot submit, day {} part {} has no answer",
+            day,
+            part
+        ));
+    }
+    let answer = answer.to_string();
+
+    let session = session.ok_or(anyhow!(
+        "Cannot submit without a session token, set --session or AOC_SESSION"
+    ))?;
+
+    let mut history = History::load(history)?;
+    history.check(day, part, &answer)?;
+
+    let response = Client::new(base_url, &session).post_answer(day, part, &answer)?;
+    let outcome = Outcome::from_response(&response)?;
+    history.record(Attempt::new(day, part, &answer, outcome.clone()))?;
+
+    println!("{answer}: {outcome}");
+
+    match outcome {
+        Outcome::Right => Ok(()),
+        _ => Err(anyhow!("Answer not accepted")),
+    }
+}
+
+fn list_params(day: u8) -> Result<()> {
+    for (key, value) in registry().get(day)?.params() {
+        println!("{key}={value}");
+    }
+
+    Ok(())
+}
+
+fn set_params(puzzle: &mut dyn Puzzle, params: &[(String, String)]) -> Result<()> {
+    params
+        .iter()
+        .try_for_each(|(key, value)| puzzle.set_param(key, value))
+}
+
+fn summarise(store: &InputStore, parallel: bool) -> ExitCode {
+    let rows = summary::run_all(&registry(), store, parallel);
+    summary::print_table(&rows);
+
+    if rows
+        .iter()
+        .any(|row| matches!(row.status, summary::Status::Error(_)))
+    {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn check(store: &InputStore, answers: &Path) -> ExitCode {
+    let known_answers = match KnownAnswers::load(answers) {
+        Ok(known_answers) => known_answers,
+        Err(error) => {
+            eprintln!("{error:?}");
+
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let verdicts = verify(&registry(), &known_answers, store);
+    for (day, part, verdict) in &verdicts {
+        println!("day {day} part {part}: {verdict}");
+    }
+
+    let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|(_, _, v)| f(v)).count();
+    let passed = count(|v| matches!(v, Verdict::Pass));
+    let failed = count(|v| matches!(v, Verdict::Fail(_)));
+    let modified = count(|v| matches!(v, Verdict::InputModified(_)));
+    let missing = count(|v| matches!(v, Verdict::Missing(_)));
+    println!("{passed} passed, {failed} failed, {modified} input modified, {missing} missing");
+
+    if failed > 0 || modified > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
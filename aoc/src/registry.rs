@@ -0,0 +1,33 @@
+use cli::Registry;
+
+/// Gets the [Registry] of all days.
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    registry.register(1, day_1::Solver);
+    registry.register(2, day_2::Solver);
+    registry.register(3, day_3::Solver);
+    registry.register(4, day_4::Solver);
+    registry.register(5, day_5::Solver);
+    registry.register(6, day_6::Solver);
+    registry.register(7, day_7::Solver);
+    registry.register(8, day_8::Solver);
+    registry.register(9, day_9::Solver);
+    registry.register(10, day_10::Solver);
+    registry.register(11, day_11::Solver::default());
+    registry.register(12, day_12::Solver);
+    registry.register(13, day_13::Solver);
+    registry.register(14, day_14::Solver::default());
+    registry.register(15, day_15::Solver);
+    registry.register(16, day_16::Solver);
+    registry.register(17, day_17::Solver);
+    registry.register(18, day_18::Solver::default());
+    registry.register(19, day_19::Solver);
+    registry.register(20, day_20::Solver::default());
+    registry.register(21, day_21::Solver::default());
+    registry.register(22, day_22::Solver::default());
+    registry.register(23, day_23::Solver);
+    registry.register(24, day_24::Solver);
+    registry.register(25, day_25::Solver);
+
+    registry
+}
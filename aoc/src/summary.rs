@@ -0,0 +1,110 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    thread,
+    time::{Duration, Instant},
+};
+
+use cli::{Answer, InputStore, Part, Puzzle, Registry, read_input};
+
+/// The outcome of solving one part of one day.
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub elapsed: Option<Duration>,
+    pub status: Status,
+}
+
+pub enum Status {
+    Ok,
+    NotApplicable,
+    MissingInput,
+    Error(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::NotApplicable => write!(f, "n/a"),
+            Self::MissingInput => write!(f, "missing input"),
+            Self::Error(error) => write!(f, "error: {error}"),
+        }
+    }
+}
+
+/// Solves both parts of every registered day, optionally with a thread per day.
+pub fn run_all(registry: &Registry, store: &InputStore, parallel: bool) -> Vec<Row> {
+    if parallel {
+        thread::scope(|scope| {
+            registry
+                .days()
+                .map(|(day, puzzle)| scope.spawn(move || run_day(day, puzzle, store)))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("Cannot join day thread"))
+                .collect()
+        })
+    } else {
+        registry
+            .days()
+            .flat_map(|(day, puzzle)| run_day(day, puzzle, store))
+            .collect()
+    }
+}
+
+fn run_day(day: u8, puzzle: &dyn Puzzle, store: &InputStore) -> [Row; 2] {
+    let path = store.path(day);
+    let input = path.exists().then(|| read_input(&path));
+
+    [Part::Part1, Part::Part2].map(|part| {
+        let (answer, elapsed, status) = match &input {
+            None => (None, None, Status::MissingInput),
+            Some(Err(error)) => (None, None, Status::Error(format!("{error:#}"))),
+            Some(Ok(input)) => {
+                let start = Instant::now();
+                let result = puzzle.solve(part, input);
+                let elapsed = Some(start.elapsed());
+
+                match result {
+                    Ok(Answer::NotApplicable) => {
+                        (Some(Answer::NotApplicable), elapsed, Status::NotApplicable)
+                    }
+                    Ok(answer) => (Some(answer), elapsed, Status::Ok),
+                    Err(error) => (None, elapsed, Status::Error(format!("{error:#}"))),
+                }
+            }
+        };
+
+        Row {
+            day,
+            part,
+            answer,
+            elapsed,
+            status,
+        }
+    })
+}
+
+/// Prints the rows as a Markdown table, like the answers table in the README.
+pub fn print_table(rows: &[Row]) {
+    println!("| Day | Part | Answer | Time | Status |");
+    println!("| :- | :- | :- | :- | :- |");
+
+    for row in rows {
+        let answer = row
+            .answer
+            .as_ref()
+            .map(Answer::to_string)
+            .unwrap_or_default();
+        let elapsed = row
+            .elapsed
+            .map(|elapsed| format!("{elapsed:.2?}"))
+            .unwrap_or_default();
+
+        println!(
+            "| {} | {} | {} | {} | {} |",
+            row.day, row.part, answer, elapsed, row.status
+        );
+    }
+}
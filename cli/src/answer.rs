@@ -0,0 +1,59 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Integer(u64),
+    BigInteger(u128),
+    Text(String),
+
+    /// The puzzle has no such part, e.g. day 25 part 2.
+    NotApplicable,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::BigInteger(value) => write!(f, "{value}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::NotApplicable => write!(f, "N.A."),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Integer(value as u64)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Self::BigInteger(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.into())
+    }
+}
@@ -0,0 +1,105 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Result, anyhow};
+#[cfg(feature = "cli")]
+use serde::{Serialize, Serializer};
+
+/// The timings of the parse and solve phases of one part of a day's puzzle.
+#[derive(Debug)]
+#[cfg_attr(feature = "cli", derive(Serialize))]
+pub struct Bench {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Summary statistics over repeated runs. Serialized in nanoseconds.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "cli", derive(Serialize))]
+pub struct Stats {
+    #[cfg_attr(feature = "cli", serde(serialize_with = "nanos"))]
+    pub min: Duration,
+
+    #[cfg_attr(feature = "cli", serde(serialize_with = "nanos"))]
+    pub median: Duration,
+
+    #[cfg_attr(feature = "cli", serde(serialize_with = "nanos"))]
+    pub mean: Duration,
+
+    #[cfg_attr(feature = "cli", serde(serialize_with = "nanos"))]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Result<Self> {
+        if samples.is_empty() {
+            return Err(anyhow!("Cannot compute statistics without samples"));
+        }
+
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let count = samples.len();
+        let median = if count.is_multiple_of(2) {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        } else {
+            samples[count / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / count as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Ok(Self {
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Runs f a number of times, and gets the time taken by each run.
+pub fn time<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Vec<Duration>> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f()?);
+
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+#[cfg(feature = "cli")]
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_from_samples() -> Result<()> {
+        let samples = [4, 2, 5, 4, 9, 4, 7, 5].map(Duration::from_secs);
+
+        assert_eq!(
+            Stats::from_samples(&samples)?,
+            Stats {
+                min: Duration::from_secs(2),
+                median: Duration::from_millis(4500),
+                mean: Duration::from_secs(5),
+                stddev: Duration::from_secs(2),
+            }
+        );
+        assert!(Stats::from_samples(&[]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    cell::Cell,
+    io::Read,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Result, anyhow};
+use ureq::{Agent, AgentBuilder, Error, Request, Response};
+
+use crate::Part;
+
+/// The default base URL of the puzzle server.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The minimum interval between requests to the puzzle server.
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const YEAR: u16 = 2024;
+const USER_AGENT: &str = "github.com/hum-ler/advent-of-rust-code-2024";
+
+/// A client for an Advent-of-Code-compatible puzzle server, authenticated with a session token.
+///
+/// Requests are spaced at least 5 seconds apart, to go easy on the server.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: String::from(base_url.trim_end_matches("/")),
+            session: String::from(session),
+            last_request: Cell::new(None),
+        }
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn fetch_input(&self, day: u8) -> Result<Vec<u8>> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+
+        let mut input = Vec::new();
+        self.send(day, self.agent.get(&url), None)?
+            .into_reader()
+            .read_to_end(&mut input)?;
+
+        Ok(input)
+    }
+
+    /// Posts the answer for one part of a day's puzzle, and gets the response page.
+    pub fn post_answer(&self, day: u8, part: Part, answer: &str) -> Result<String> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+
+        Ok(self
+            .send(
+                day,
+                self.agent.post(&url),
+                Some(&[("level", &level), ("answer", answer)]),
+            )?
+            .into_string()?)
+    }
+
+    /// Sends a request with the session cookie, and the form if any, after waiting out the minimum
+    /// interval. Maps the error responses of the puzzle server.
+    fn send(&self, day: u8, request: Request, form: Option<&[(&str, &str)]>) -> Result<Response> {
+        self.throttle();
+
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+
+        match response {
+            Ok(response) => Ok(response),
+            Err(Error::Status(404, _)) => {
+                Err(anyhow!("Cannot find puzzle, day {} not yet released", day))
+            }
+            Err(Error::Status(429, response)) => Err(anyhow!(
+                "Cannot send request, rate limited by the server (retry after {}s)",
+                response.header("Retry-After").unwrap_or("?")
+            )),
+            Err(Error::Status(status, response)) => {
+                // An expired or invalid session gets a page that asks to log in.
+                match response.into_string() {
+                    Ok(body) if body.contains("log in") => {
+                        Err(anyhow!("Cannot authenticate, session expired or invalid"))
+                    }
+                    _ => Err(anyhow!("Cannot send request, status: {}", status)),
+                }
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn throttle(&self) {
+        if let Some(last_request) = self.last_request.get() {
+            let elapsed = last_request.elapsed();
+            if elapsed < MIN_INTERVAL {
+                thread::sleep(MIN_INTERVAL - elapsed);
+            }
+        }
+
+        self.last_request.set(Some(Instant::now()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::JoinHandle;
+
+    use tiny_http::{Header, Response, Server};
+
+    use super::*;
+
+    /// (url, cookie, body)
+    type ReceivedRequest = (String, String, String);
+
+    /// Serves a single canned response, and gets the URL, cookie and body of the request.
+    fn mock_server(
+        status: u16,
+        body: &'static str,
+        headers: &'static [(&'static str, &'static str)],
+    ) -> Result<(String, JoinHandle<ReceivedRequest>)> {
+        let server = Server::http("127.0.0.1:0").map_err(|error| anyhow!(error))?;
+        let base_url = format!("http://{}", server.server_addr());
+
+        let handle = thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let url = String::from(request.url());
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Cookie"))
+                .map(|header| header.value.to_string())
+                .unwrap_or_default();
+            let mut request_body = String::new();
+            request
+                .as_reader()
+                .read_to_string(&mut request_body)
+                .unwrap();
+
+            let mut response = Response::from_string(body).with_status_code(status);
+            for (field, value) in headers {
+                response.add_header(Header::from_bytes(*field, *value).unwrap());
+            }
+            request.respond(response).unwrap();
+
+            (url, cookie, request_body)
+        });
+
+        Ok((base_url, handle))
+    }
+
+    #[test]
+    fn fetch_input() -> Result<()> {
+        let (base_url, handle) = mock_server(200, "125 17\n", &[])?;
+
+        let input = Client::new(&base_url, "abc").fetch_input(11)?;
+
+        assert_eq!(input, b"125 17\n");
+        assert_eq!(
+            handle.join().unwrap(),
+            (
+                String::from("/2024/day/11/input"),
+                String::from("session=abc"),
+                String::new()
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn post_answer() -> Result<()> {
+        let (base_url, handle) = mock_server(200, "<p>That's the right answer!</p>", &[])?;
+
+        let response = Client::new(&base_url, "abc").post_answer(1, Part::Part2, "31")?;
+
+        assert!(response.contains("right answer"));
+        let (url, _, body) = handle.join().unwrap();
+        assert_eq!(url, "/2024/day/1/answer");
+        assert_eq!(body, "level=2&answer=31");
+
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_input_with_expired_session() -> Result<()> {
+        let (base_url, _) = mock_server(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            &[],
+        )?;
+
+        let error = Client::new(&base_url, "abc").fetch_input(1).unwrap_err();
+        assert!(error.to_string().contains("session expired"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_unreleased_input() -> Result<()> {
+        let (base_url, _) = mock_server(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+            &[],
+        )?;
+
+        let error = Client::new(&base_url, "abc").fetch_input(25).unwrap_err();
+        assert!(error.to_string().contains("not yet released"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fetch_input_when_rate_limited() -> Result<()> {
+        let (base_url, _) = mock_server(429, "", &[("Retry-After", "60")])?;
+
+        let error = Client::new(&base_url, "abc").fetch_input(1).unwrap_err();
+        assert!(error.to_string().contains("retry after 60s"));
+
+        Ok(())
+    }
+}
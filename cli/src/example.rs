@@ -0,0 +1,231 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow};
+
+use crate::{Part, Puzzle, parse_param, read_input};
+
+/// A puzzle example, with the expected answer for each part, where known.
+pub struct Example {
+    pub input: String,
+
+    /// The tunable parameters that the example needs, e.g. a smaller grid size.
+    pub params: Vec<(String, String)>,
+
+    answers: BTreeMap<Part, String>,
+}
+
+impl Example {
+    /// Loads an example and its expected answers from `examples/day-<day>/`.
+    pub fn load(day: u8, name: &str) -> Result<Self> {
+        Self::load_in(Path::new(""), day, name)
+    }
+
+    /// Loads an example like [Example::load], but from the workspace at the root, rather than the
+    /// current directory. See `load_example!` for a day's tests.
+    pub fn load_in(root: &Path, day: u8, name: &str) -> Result<Self> {
+        let dir = root.join(example_dir(day));
+        let input = read_input(&dir.join(format!("{name}.txt")))?;
+
+        let path = dir.join("answers.txt");
+        let answers = if path.exists() {
+            let answers = fs::read_to_string(&path)
+                .with_context(|| format!("Cannot read example answers file: {}", path.display()))?;
+
+            answer_lines(&answers)
+                .filter(|line| !matches!(line, Ok((example, _, _)) if *example != name))
+                .map(|line| line.map(|(_, part, answer)| (part, String::from(answer))))
+                .collect::<Result<_>>()?
+        } else {
+            BTreeMap::new()
+        };
+
+        let path = dir.join("params.txt");
+        let params = if path.exists() {
+            let params = fs::read_to_string(&path)
+                .with_context(|| format!("Cannot read example params file: {}", path.display()))?;
+
+            params_lines(&params)
+                .filter(|line| !matches!(line, Ok((example, _)) if *example != name))
+                .map(|line| line.map(|(_, param)| param))
+                .collect::<Result<_>>()?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            input,
+            params,
+            answers,
+        })
+    }
+
+    /// Gets the expected answer for a part, if known.
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers.get(&part).map(String::as_str)
+    }
+
+    /// Solves a part of the example, with its parameters, and checks the expected answer.
+    pub fn check(&self, puzzle: &mut dyn Puzzle, part: Part) -> Result<()> {
+        for (key, value) in &self.params {
+            puzzle.set_param(key, value)?;
+        }
+
+        let Some(expected) = self.answer(part) else {
+            return Err(anyhow!("Cannot find example answer for part {}", part));
+        };
+        let answer = puzzle.solve(part, &self.input)?.to_string();
+        if answer != expected {
+            return Err(anyhow!(
+                "Example answer mismatch, expected {} but got {}",
+                expected,
+                answer
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Loads an example of a day from the workspace root, wherever the calling crate is tested from.
+///
+/// Unit tests run in their crate's directory, so `load_example!(17, "part-1")` finds the example
+/// relative to the crate's manifest, rather than the current directory like [Example::load].
+#[macro_export]
+macro_rules! load_example {
+    ($day:expr, $name:expr) => {
+        $crate::Example::load_in(
+            ::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/..")),
+            $day,
+            $name,
+        )
+    };
+}
+
+/// Parses the lines of an answers file, skipping blank lines and comments.
+///
+/// Each line is a key, a part number, and the answer, separated by whitespace.
+pub(crate) fn answer_lines(s: &str) -> impl Iterator<Item = Result<(&str, Part, &str)>> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("#"))
+        .map(|line| {
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            if tokens.len() != 3 {
+                return Err(anyhow!("Invalid answer line: {}", line));
+            }
+
+            Ok((
+                tokens[0],
+                Part::try_from(tokens[1].parse::<u8>()?)?,
+                tokens[2],
+            ))
+        })
+}
+
+/// Parses the lines of a params file, skipping blank lines and comments.
+///
+/// Each line is an example name and a key=value pair, separated by whitespace.
+fn params_lines(s: &str) -> impl Iterator<Item = Result<(&str, (String, String))>> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("#"))
+        .map(|line| {
+            let Some((example, param)) = line.split_once(char::is_whitespace) else {
+                return Err(anyhow!("Invalid params line: {}", line));
+            };
+
+            Ok((example, parse_param(param.trim())?))
+        })
+}
+
+/// Gets the examples directory for a day.
+pub fn example_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("examples/day-{day}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use crate::{Answer, Solution};
+
+    use super::*;
+
+    /// Gives the same answer to both parts, which is set as a parameter.
+    struct Fixed(u32);
+
+    impl Solution for Fixed {
+        type Input<'a> = ();
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Input<'a>> {
+            Ok(())
+        }
+
+        fn part_1(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+            Ok(Answer::from(self.0))
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+            Ok(Answer::from(self.0))
+        }
+
+        fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+            match key {
+                "answer" => self.0 = value.parse()?,
+                _ => return Err(anyhow!("Invalid parameter: {}", key)),
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn check_example_answer() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-example-{}", process::id()));
+        let dir = root.join(example_dir(1));
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("example.txt"), "\n1 2 3\n")?;
+        fs::write(dir.join("other.txt"), "4 5 6")?;
+        fs::write(
+            dir.join("answers.txt"),
+            "# Comment\n\nexample 1 42\nexample 2 41\nother 1 7\n",
+        )?;
+        fs::write(
+            dir.join("params.txt"),
+            "example answer=42\nother answer=7\n",
+        )?;
+
+        let example = Example::load_in(&root, 1, "example")?;
+        assert_eq!(example.input, "1 2 3");
+        assert_eq!(
+            example.params,
+            [(String::from("answer"), String::from("42"))]
+        );
+        assert_eq!(example.answer(Part::Part1), Some("42"));
+        assert_eq!(example.answer(Part::Part2), Some("41"));
+
+        // The example sets the answer to 42, which matches part 1 but not part 2.
+        example.check(&mut Fixed(0), Part::Part1)?;
+        assert_eq!(
+            example
+                .check(&mut Fixed(0), Part::Part2)
+                .map_err(|error| error.to_string()),
+            Err(String::from(
+                "Example answer mismatch, expected 41 but got 42"
+            ))
+        );
+
+        let example = Example::load_in(&root, 1, "other")?;
+        example.check(&mut Fixed(0), Part::Part1)?;
+        assert_eq!(example.answer(Part::Part2), None);
+        assert!(example.check(&mut Fixed(0), Part::Part2).is_err());
+
+        fs::remove_dir_all(&root)?;
+
+        Ok(())
+    }
+}
@@ -1,36 +1,201 @@
-use std::{fs, path::PathBuf};
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
 use clap::Parser;
 
+pub use answer::Answer;
+pub use bench::{Bench, Stats};
+#[cfg(feature = "cli")]
+pub use client::{Client, DEFAULT_BASE_URL};
+pub use example::{Example, example_dir};
+pub use parse::{ParseError, parse_fragment, parse_lines, parse_section};
+pub use registry::Registry;
+pub use solution::{Puzzle, Solution};
+pub use store::{DEFAULT_INPUT_DIR, InputStore};
+#[cfg(feature = "cli")]
+pub use store::{Integrity, checksum};
+#[cfg(feature = "cli")]
+pub use submit::{Attempt, History, Outcome};
+#[cfg(feature = "cli")]
+pub use verify::{KnownAnswers, Verdict, verify};
+
+mod answer;
+mod bench;
+#[cfg(feature = "cli")]
+mod client;
+mod example;
+mod parse;
+mod registry;
+mod solution;
+mod store;
+#[cfg(feature = "cli")]
+mod submit;
+#[cfg(feature = "cli")]
+mod verify;
+
+/// The command-line arguments for solving one part of a day's puzzle.
+///
+/// Days with extra subcommands can flatten these into their own arguments.
 #[derive(Parser)]
-struct Args {
+pub struct RunArgs {
     part: u8,
 
     #[arg(short = 'i', long)]
     input: Option<PathBuf>,
+
+    /// The root directory of the input store.
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+
+    /// Sets a tunable parameter of the puzzle.
+    #[arg(short = 'p', long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Part {
-    Part1(String),
-    Part2(String),
+    Part1,
+    Part2,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Part1),
+            2 => Ok(Self::Part2),
+            _ => Err(anyhow!("Invalid part number: {}", value)),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Part1 => write!(f, "1"),
+            Self::Part2 => write!(f, "2"),
+        }
+    }
+}
+
+/// Runs the [Puzzle] for a single day, taking the part and input path from the command line.
+pub fn run(puzzle: &mut dyn Puzzle, day: u8) -> ExitCode {
+    run_with_args(puzzle, day, RunArgs::parse())
+}
+
+/// Runs the [Puzzle] for a single day, with arguments that have already been parsed.
+pub fn run_with_args(puzzle: &mut dyn Puzzle, day: u8, args: RunArgs) -> ExitCode {
+    report(solve(puzzle, day, args))
+}
+
+/// Prints the answer to stdout, or the error to stderr, and gets the matching [ExitCode].
+pub fn report(result: Result<Answer>) -> ExitCode {
+    match result {
+        Ok(answer) => {
+            println!("{answer}");
+
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error:?}");
+
+            ExitCode::FAILURE
+        }
+    }
 }
 
-/// Gets the [Part] to execute.
-pub fn get_part(default_input: &str) -> Result<Part> {
-    let args = Args::parse();
+/// Prints the error to stderr, if any, and gets the matching [ExitCode].
+pub fn report_error(result: Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error:?}");
 
-    let path = args.input.unwrap_or(default_input.into());
-    let input = String::from(trim_newlines(&fs::read_to_string(path)?));
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    match args.part {
-        1 => Ok(Part::Part1(input)),
-        2 => Ok(Part::Part2(input)),
-        _ => Err(anyhow!("Invalid part number: {}", args.part)),
+fn solve(puzzle: &mut dyn Puzzle, day: u8, args: RunArgs) -> Result<Answer> {
+    for (key, value) in &args.params {
+        puzzle.set_param(key, value)?;
     }
+
+    let part = Part::try_from(args.part)?;
+    let path = args
+        .input
+        .unwrap_or(InputStore::new(args.input_dir).path(day));
+    let input = read_input(&path)?;
+
+    puzzle.solve(part, &input)
+}
+
+/// Parses a tunable parameter from the command line, in the form key=value.
+pub fn parse_param(s: &str) -> Result<(String, String)> {
+    let (key, value) = s
+        .split_once("=")
+        .ok_or(anyhow!("Invalid parameter, expected key=value: {}", s))?;
+
+    Ok((String::from(key), String::from(value)))
+}
+
+/// Parses the width or height of a grid from a tunable parameter, which must be at least 1.
+pub fn parse_grid_length(value: &str) -> Result<usize> {
+    match value.parse()? {
+        0 => Err(anyhow!("Invalid grid size: {}", value)),
+        length => Ok(length),
+    }
+}
+
+/// Reads the puzzle input from a file, or from stdin if the path is "-".
+///
+/// The input is normalised, with surrounding newlines trimmed.
+pub fn read_input(path: &Path) -> Result<String> {
+    let input = if path == Path::new("-") {
+        io::read_to_string(io::stdin()).context("Cannot read input from stdin")?
+    } else {
+        fs::read_to_string(path)
+            .with_context(|| format!("Cannot read input file: {}", path.display()))?
+    };
+
+    Ok(String::from(trim_newlines(&normalise_input(&input))))
+}
+
+/// Strips the UTF-8 byte order mark, if any, and converts CRLF line endings to LF.
+pub fn normalise_input(input: &str) -> String {
+    input.trim_start_matches('\u{feff}').replace("\r\n", "\n")
 }
 
 /// Trims newlines from the start and the end of the input string.
 pub fn trim_newlines(input: &str) -> &str {
     input.trim_start_matches("\n").trim_end_matches("\n")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalise_crlf_and_bom() {
+        assert_eq!(
+            normalise_input("\u{feff}a\r\nb\r\n\r\nc\r\n"),
+            "a\nb\n\nc\n"
+        );
+        assert_eq!(normalise_input("a\nb"), "a\nb");
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use anyhow::Result;
+
+/// An error in the puzzle input, located by line and column (both counted from 1).
+///
+/// It is shown with the line at fault, and carets under the part that could not be parsed.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseError {
+    message: String,
+    line: usize,
+    column: usize,
+    width: usize,
+    source_line: String,
+}
+
+impl ParseError {
+    /// Locates the fragment in the input. The fragment should be a slice of the input, or else
+    /// its first occurrence is used.
+    pub fn at(input: &str, fragment: &str, message: impl Display) -> Self {
+        let (line, column) = position(input, offset(input, fragment));
+
+        Self {
+            message: message.to_string(),
+            line,
+            column,
+            width: fragment.lines().next().unwrap_or_default().chars().count(),
+            source_line: source_line(input, line),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Moves the error from a section of the input, which it was located in, to the whole input.
+    fn relocate(self, input: &str, section: &str) -> Self {
+        let (section_line, section_column) = position(input, offset(input, section));
+
+        let line = section_line + self.line - 1;
+        let column = if self.line == 1 {
+            section_column + self.column - 1
+        } else {
+            self.column
+        };
+
+        Self {
+            line,
+            column,
+            source_line: source_line(input, line),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width.max(1))
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a section of the input, such as a line or a block, and locates any error in the whole
+/// input.
+///
+/// A [ParseError] from the parser is located relative to the section. Any other error is located
+/// at the whole section.
+pub fn parse_section<'a, T>(
+    input: &'a str,
+    section: &'a str,
+    parser: impl FnOnce(&'a str) -> Result<T>,
+) -> Result<T> {
+    parser(section).map_err(|error| match error.downcast::<ParseError>() {
+        Ok(error) => error.relocate(input, section).into(),
+        Err(error) => ParseError::at(input, section, format!("{error:#}")).into(),
+    })
+}
+
+/// Parses each line of the input, and locates any error in the whole input.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> Result<T>,
+) -> Result<Vec<T>> {
+    input
+        .lines()
+        .map(|line| parse_section(input, line, &mut parser))
+        .collect()
+}
+
+/// Parses a fragment of the input, such as a number, and locates any error in the input.
+pub fn parse_fragment<T>(input: &str, fragment: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fragment.parse().map_err(|error| {
+        ParseError::at(input, fragment, format!("Cannot parse value: {error}")).into()
+    })
+}
+
+/// Gets the byte offset of the fragment in the input.
+fn offset(input: &str, fragment: &str) -> usize {
+    (fragment.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|offset| offset + fragment.len() <= input.len())
+        .or_else(|| input.find(fragment))
+        .unwrap_or_default()
+}
+
+/// Gets the line and column of the byte offset in the input.
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line_start = before.rfind("\n").map_or(0, |index| index + 1);
+
+    (
+        before.matches("\n").count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn source_line(input: &str, line: usize) -> String {
+    String::from(input.lines().nth(line - 1).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    const INPUT: &str = "3   4\n4   x\n2   5";
+
+    #[test]
+    fn locate_fragment() {
+        let error = ParseError::at(INPUT, &INPUT[10..11], "Invalid value");
+
+        assert_eq!((error.line(), error.column()), (2, 5));
+        assert_eq!(
+            error.to_string(),
+            "Invalid value at line 2, column 5\n2 | 4   x\n  |     ^"
+        );
+    }
+
+    #[test]
+    fn locate_errors_in_lines() {
+        let error = parse_lines(INPUT, |line| {
+            let (left, right) = line.split_once("   ").ok_or(anyhow!("Cannot split"))?;
+
+            Ok((
+                parse_fragment::<u32>(line, left)?,
+                parse_fragment::<u32>(line, right)?,
+            ))
+        })
+        .unwrap_err()
+        .downcast::<ParseError>()
+        .unwrap();
+        assert_eq!((error.line(), error.column()), (2, 5));
+        assert_eq!(
+            error.message(),
+            "Cannot parse value: invalid digit found in string"
+        );
+
+        let error = parse_lines(INPUT, |line| Ok(line.parse::<u32>()?))
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((error.line(), error.column()), (1, 1));
+        assert_eq!(error.message(), "invalid digit found in string");
+        assert!(error.to_string().ends_with("1 | 3   4\n  | ^^^^^"));
+    }
+}
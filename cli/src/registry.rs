@@ -0,0 +1,38 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Result, anyhow};
+
+use crate::Puzzle;
+
+/// Looks up the puzzle for each day by day number.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u8, Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    pub fn register(&mut self, day: u8, puzzle: impl Puzzle + 'static) {
+        self.days.insert(day, Box::new(puzzle));
+    }
+
+    pub fn get(&self, day: u8) -> Result<&dyn Puzzle> {
+        self.days
+            .get(&day)
+            .map(Box::as_ref)
+            .ok_or(anyhow!("Invalid day number: {}", day))
+    }
+
+    pub fn get_mut(&mut self, day: u8) -> Result<&mut (dyn Puzzle + 'static)> {
+        self.days
+            .get_mut(&day)
+            .map(Box::as_mut)
+            .ok_or(anyhow!("Invalid day number: {}", day))
+    }
+
+    /// Iterates over the registered days in order.
+    pub fn days(&self) -> impl Iterator<Item = (u8, &dyn Puzzle)> {
+        self.days
+            .iter()
+            .map(|(day, puzzle)| (*day, puzzle.as_ref()))
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::{Result, anyhow};
+
+use crate::{Answer, Bench, Part, Stats, bench::time};
+
+/// A solution to a day's puzzle.
+///
+/// The input is parsed once and then shared by both parts.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>>;
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer>;
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer>;
+
+    /// Gets the tunable parameters, with their current values.
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// Sets a tunable parameter, parsing the value from a string.
+    fn set_param(&mut self, key: &str, _value: &str) -> Result<()> {
+        Err(anyhow!("Invalid parameter: {}", key))
+    }
+}
+
+/// An object-safe view of a [Solution], so that days with different inputs can be handled alike.
+pub trait Puzzle: Send + Sync {
+    /// Parses the input and solves one part of the puzzle.
+    fn solve(&self, part: Part, input: &str) -> Result<Answer>;
+
+    /// Times the parse and solve phases of one part separately, over a number of iterations.
+    fn bench(&self, part: Part, input: &str, iterations: usize) -> Result<Bench>;
+
+    /// Gets the tunable parameters, with their current values.
+    fn params(&self) -> Vec<(&'static str, String)>;
+
+    /// Sets a tunable parameter, parsing the value from a string.
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()>;
+}
+
+impl<T: Solution + Send + Sync> Puzzle for T {
+    fn solve(&self, part: Part, input: &str) -> Result<Answer> {
+        let input = self.parse(input)?;
+
+        match part {
+            Part::Part1 => self.part_1(&input),
+            Part::Part2 => self.part_2(&input),
+        }
+    }
+
+    fn bench(&self, part: Part, input: &str, iterations: usize) -> Result<Bench> {
+        let parse = time(iterations, || self.parse(input))?;
+
+        let input = self.parse(input)?;
+        let solve = time(iterations, || match part {
+            Part::Part1 => self.part_1(&input),
+            Part::Part2 => self.part_2(&input),
+        })?;
+
+        Ok(Bench {
+            parse: Stats::from_samples(&parse)?,
+            solve: Stats::from_samples(&solve)?,
+        })
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Solution::params(self)
+    }
+
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+        Solution::set_param(self, key, value)
+    }
+}
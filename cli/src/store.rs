@@ -0,0 +1,192 @@
+#[cfg(feature = "cli")]
+use std::collections::BTreeMap;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "cli")]
+use anyhow::anyhow;
+use anyhow::{Context, Result};
+#[cfg(feature = "cli")]
+use sha2::{Digest, Sha256};
+
+/// The default root directory of the [InputStore].
+pub const DEFAULT_INPUT_DIR: &str = "input";
+
+#[cfg(feature = "cli")]
+const MANIFEST: &str = "manifest.txt";
+
+/// A directory of puzzle inputs, one file per day, with a manifest of their SHA-256 checksums.
+pub struct InputStore {
+    root: PathBuf,
+}
+
+/// Whether an input file still matches its checksum in the manifest.
+#[cfg(feature = "cli")]
+#[derive(Debug, Eq, PartialEq)]
+pub enum Integrity {
+    Missing,
+    Unrecorded,
+    Unchanged,
+    Modified,
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Gets the input file path for a day.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.root.join(format!("day-{day}.txt"))
+    }
+
+    /// Gets the days that have an input file, in order.
+    pub fn days(&self) -> Result<Vec<u8>> {
+        if !self.root.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut days = fs::read_dir(&self.root)
+            .with_context(|| format!("Cannot read input directory: {}", self.root.display()))?
+            .filter_map(|entry| {
+                entry
+                    .ok()?
+                    .file_name()
+                    .to_str()?
+                    .strip_prefix("day-")?
+                    .strip_suffix(".txt")?
+                    .parse()
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        days.sort();
+
+        Ok(days)
+    }
+}
+
+#[cfg(feature = "cli")]
+impl InputStore {
+    /// Copies an input file into the store, and records its checksum in the manifest.
+    pub fn add(&self, day: u8, source: &Path) -> Result<String> {
+        let contents = fs::read(source)
+            .with_context(|| format!("Cannot read input file: {}", source.display()))?;
+
+        self.insert(day, &contents)
+    }
+
+    /// Writes the input for a day into the store, and records its checksum in the manifest.
+    pub fn insert(&self, day: u8, contents: &[u8]) -> Result<String> {
+        fs::create_dir_all(&self.root)
+            .with_context(|| format!("Cannot create input directory: {}", self.root.display()))?;
+
+        let path = self.path(day);
+        fs::write(&path, contents)
+            .with_context(|| format!("Cannot write input file: {}", path.display()))?;
+
+        let checksum = checksum(contents);
+        let mut manifest = self.manifest()?;
+        manifest.insert(day, checksum.clone());
+        self.write_manifest(&manifest)?;
+
+        Ok(checksum)
+    }
+
+    /// Checks the input file for a day against its checksum in the manifest.
+    pub fn integrity(&self, day: u8) -> Result<Integrity> {
+        let path = self.path(day);
+        if !path.exists() {
+            return Ok(Integrity::Missing);
+        }
+
+        let contents = fs::read(&path)
+            .with_context(|| format!("Cannot read input file: {}", path.display()))?;
+
+        Ok(match self.manifest()?.get(&day) {
+            None => Integrity::Unrecorded,
+            Some(recorded) if *recorded == checksum(&contents) => Integrity::Unchanged,
+            Some(_) => Integrity::Modified,
+        })
+    }
+
+    /// Gets the recorded checksum of each day's input.
+    pub fn manifest(&self) -> Result<BTreeMap<u8, String>> {
+        let path = self.root.join(MANIFEST);
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        fs::read_to_string(&path)
+            .with_context(|| format!("Cannot read manifest: {}", path.display()))?
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with("#"))
+            .map(|line| {
+                let Some((day, checksum)) = line.split_once(" ") else {
+                    return Err(anyhow!("Invalid manifest line: {}", line));
+                };
+
+                Ok((day.parse()?, String::from(checksum)))
+            })
+            .collect()
+    }
+
+    fn write_manifest(&self, manifest: &BTreeMap<u8, String>) -> Result<()> {
+        let path = self.root.join(MANIFEST);
+        let contents = manifest
+            .iter()
+            .map(|(day, checksum)| format!("{day} {checksum}\n"))
+            .collect::<String>();
+
+        fs::write(&path, contents)
+            .with_context(|| format!("Cannot write manifest: {}", path.display()))
+    }
+}
+
+#[cfg(feature = "cli")]
+/// Gets the SHA-256 checksum of the contents, as a hex string.
+pub fn checksum(contents: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(contents))
+}
+
+#[cfg(all(test, feature = "cli"))]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn add_and_check_inputs() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-input-store-{}", std::process::id()));
+        let store = InputStore::new(&root);
+
+        assert_eq!(store.days()?, Vec::<u8>::new());
+        assert_eq!(store.integrity(1)?, Integrity::Missing);
+
+        store.insert(1, b"3   4\n")?;
+        fs::write(store.path(2), "125 17\n")?;
+        assert_eq!(store.days()?, vec![1, 2]);
+        assert_eq!(store.integrity(1)?, Integrity::Unchanged);
+        assert_eq!(store.integrity(2)?, Integrity::Unrecorded);
+
+        fs::write(store.path(1), "4   3\n")?;
+        assert_eq!(store.integrity(1)?, Integrity::Modified);
+
+        fs::remove_dir_all(&root)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn sha256_checksum() {
+        assert_eq!(
+            checksum(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
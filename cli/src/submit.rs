@@ -0,0 +1,318 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result, anyhow};
+
+use crate::Part;
+
+/// The outcome of submitting an answer to the puzzle server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+
+    /// Submitted too soon after a previous attempt, with the time left to wait.
+    Wait(String),
+
+    /// The part has already been solved, or is not yet unlocked.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Parses the outcome from the response page of the puzzle server.
+    pub fn from_response(response: &str) -> Result<Self> {
+        if response.contains("That's the right answer") {
+            Ok(Self::Right)
+        } else if response.contains("That's not the right answer") {
+            if response.contains("too high") {
+                Ok(Self::TooHigh)
+            } else if response.contains("too low") {
+                Ok(Self::TooLow)
+            } else {
+                Ok(Self::Wrong)
+            }
+        } else if response.contains("You gave an answer too recently") {
+            let wait = response
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.replace(" ", ""))
+                .unwrap_or(String::from("?"));
+
+            Ok(Self::Wait(wait))
+        } else if response.contains("You don't seem to be solving the right level") {
+            Ok(Self::WrongLevel)
+        } else {
+            Err(anyhow!(
+                "Cannot understand the response of the puzzle server"
+            ))
+        }
+    }
+
+    /// Checks whether the outcome rules out the answer.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Right => write!(f, "right"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "too-high"),
+            Self::TooLow => write!(f, "too-low"),
+            Self::Wait(wait) => write!(f, "wait:{wait}"),
+            Self::WrongLevel => write!(f, "wrong-level"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "right" => Ok(Self::Right),
+            "wrong" => Ok(Self::Wrong),
+            "too-high" => Ok(Self::TooHigh),
+            "too-low" => Ok(Self::TooLow),
+            "wrong-level" => Ok(Self::WrongLevel),
+            _ => s
+                .strip_prefix("wait:")
+                .map(|wait| Self::Wait(String::from(wait)))
+                .ok_or(anyhow!("Invalid outcome: {}", s)),
+        }
+    }
+}
+
+/// One submission of an answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    pub fn new(day: u8, part: Part, answer: &str, outcome: Outcome) -> Self {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+
+        Self {
+            time,
+            day,
+            part,
+            answer: String::from(answer),
+            outcome,
+        }
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.time, self.day, self.part, self.answer, self.outcome
+        )
+    }
+}
+
+impl FromStr for Attempt {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+        if tokens.len() != 5 {
+            return Err(anyhow!("Invalid attempt: {}", s));
+        }
+
+        Ok(Self {
+            time: tokens[0].parse()?,
+            day: tokens[1].parse()?,
+            part: Part::try_from(tokens[2].parse::<u8>()?)?,
+            answer: String::from(tokens[3]),
+            outcome: tokens[4].parse()?,
+        })
+    }
+}
+
+/// The local history of every submission, one [Attempt] per line.
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history from a file, which need not exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        let attempts = if path.exists() {
+            fs::read_to_string(path)
+                .with_context(|| format!("Cannot read history file: {}", path.display()))?
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(Attempt::from_str)
+                .collect::<Result<_>>()?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            path: PathBuf::from(path),
+            attempts,
+        })
+    }
+
+    /// Checks the answer against the previous attempts, and refuses it if it is already solved or
+    /// known to be wrong.
+    ///
+    /// Also refuses an empty answer, or one with whitespace, as it could not be read back from the
+    /// history file.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<()> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(anyhow!("Invalid answer: {:?}", answer));
+        }
+
+        let number = answer.parse::<u128>().ok();
+
+        self.attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part)
+            .try_for_each(|attempt| {
+                let previous = attempt.answer.parse::<u128>().ok();
+
+                match (&attempt.outcome, number, previous) {
+                    (Outcome::Right, _, _) => Err(anyhow!(
+                        "Cannot submit, already solved with: {}",
+                        attempt.answer
+                    )),
+                    (outcome, _, _) if outcome.is_wrong() && attempt.answer == answer => Err(
+                        anyhow!("Cannot submit, answer already known to be {}", outcome),
+                    ),
+                    (Outcome::TooHigh, Some(number), Some(previous)) if number > previous => {
+                        Err(anyhow!(
+                            "Cannot submit, {} is already known to be too high",
+                            previous
+                        ))
+                    }
+                    (Outcome::TooLow, Some(number), Some(previous)) if number < previous => Err(
+                        anyhow!("Cannot submit, {} is already known to be too low", previous),
+                    ),
+                    _ => Ok(()),
+                }
+            })
+    }
+
+    /// Appends an attempt to the history file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Cannot open history file: {}", self.path.display()))?;
+        writeln!(file, "{attempt}")?;
+
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_outcomes() -> Result<()> {
+        assert_eq!(
+            Outcome::from_response(
+                "<p>That's the right answer! You are one gold star closer.</p>"
+            )?,
+            Outcome::Right
+        );
+        assert_eq!(
+            Outcome::from_response("<p>That's not the right answer; your answer is too high.</p>")?,
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::from_response("<p>That's not the right answer; your answer is too low.</p>")?,
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::from_response("<p>That's not the right answer. If you're stuck, ...</p>")?,
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::from_response(
+                "<p>You gave an answer too recently. You have 4m 32s left to wait.</p>"
+            )?,
+            Outcome::Wait(String::from("4m32s"))
+        );
+        assert_eq!(
+            Outcome::from_response("<p>You don't seem to be solving the right level.</p>")?,
+            Outcome::WrongLevel
+        );
+        assert!(Outcome::from_response("<p>Hello</p>").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn refuse_known_wrong_answers() -> Result<()> {
+        let history = History {
+            path: PathBuf::new(),
+            attempts: [
+                "1 7 1 500 too-high",
+                "2 7 1 100 too-low",
+                "3 7 1 300 wrong",
+                "4 7 2 abc wrong",
+                "5 8 1 42 right",
+            ]
+            .into_iter()
+            .map(Attempt::from_str)
+            .collect::<Result<_>>()?,
+        };
+
+        assert!(history.check(7, Part::Part1, "500").is_err());
+        assert!(history.check(7, Part::Part1, "600").is_err());
+        assert!(history.check(7, Part::Part1, "50").is_err());
+        assert!(history.check(7, Part::Part1, "300").is_err());
+        assert!(history.check(7, Part::Part1, "200").is_ok());
+        assert!(history.check(7, Part::Part2, "abc").is_err());
+        assert!(history.check(7, Part::Part2, "abd").is_ok());
+        assert!(history.check(8, Part::Part1, "43").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn refuse_answers_that_cannot_be_recorded() -> Result<()> {
+        let history = History {
+            path: PathBuf::new(),
+            attempts: Vec::new(),
+        };
+
+        assert!(history.check(7, Part::Part1, "").is_err());
+        assert!(history.check(7, Part::Part1, "12 34").is_err());
+        assert!(history.check(7, Part::Part1, "1234\n").is_err());
+        assert!(history.check(7, Part::Part1, "1234").is_ok());
+
+        Ok(())
+    }
+}
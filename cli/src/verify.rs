@@ -0,0 +1,132 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    fs,
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+
+use crate::{InputStore, Integrity, Part, Registry, example::answer_lines, read_input};
+
+/// Known answers, keyed by day and part.
+#[derive(Default)]
+pub struct KnownAnswers(BTreeMap<(u8, Part), String>);
+
+impl FromStr for KnownAnswers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        answer_lines(s)
+            .map(|line| {
+                let (day, part, answer) = line?;
+
+                Ok(((day.parse()?, part), String::from(answer)))
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+impl KnownAnswers {
+    /// Loads the known answers from a file.
+    pub fn load(path: &Path) -> Result<Self> {
+        fs::read_to_string(path)
+            .with_context(|| format!("Cannot read known answers file: {}", path.display()))?
+            .parse()
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// The outcome of checking one part of a day's puzzle against its known answer.
+pub enum Verdict {
+    Pass,
+    Fail(String),
+
+    /// A failure where the input file no longer matches its checksum in the manifest, so the known
+    /// answer may not apply.
+    InputModified(String),
+
+    Missing(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail(reason) => write!(f, "fail ({reason})"),
+            Self::InputModified(reason) => write!(f, "input modified ({reason})"),
+            Self::Missing(reason) => write!(f, "missing ({reason})"),
+        }
+    }
+}
+
+/// Solves every part of every registered day and checks the answers against the known answers.
+pub fn verify(
+    registry: &Registry,
+    known_answers: &KnownAnswers,
+    store: &InputStore,
+) -> Vec<(u8, Part, Verdict)> {
+    registry
+        .days()
+        .flat_map(|(day, puzzle)| {
+            let path = store.path(day);
+            let input = path.exists().then(|| read_input(&path));
+            let fail = if matches!(store.integrity(day), Ok(Integrity::Modified)) {
+                Verdict::InputModified
+            } else {
+                Verdict::Fail
+            };
+
+            [Part::Part1, Part::Part2].map(|part| {
+                let verdict = match (&input, known_answers.get(day, part)) {
+                    (None, _) => Verdict::Missing(format!("no input: {}", path.display())),
+                    (_, None) => Verdict::Missing(String::from("no known answer")),
+                    (Some(Err(error)), _) => Verdict::Fail(format!("{error:#}")),
+                    (Some(Ok(input)), Some(expected)) => match puzzle.solve(part, input) {
+                        Ok(answer) if answer.to_string() == expected => Verdict::Pass,
+                        Ok(answer) => fail(format!("expected {expected}, got {answer}")),
+                        Err(error) => fail(format!("{error:#}")),
+                    },
+                };
+
+                (day, part, verdict)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_known_answers() -> Result<()> {
+        let known_answers = KnownAnswers::from_str(
+            r"
+# day part answer
+17 1 6,0,6,3,0,2,3,1,6
+25 2 N.A.
+",
+        )?;
+
+        assert_eq!(
+            known_answers.get(17, Part::Part1),
+            Some("6,0,6,3,0,2,3,1,6")
+        );
+        assert_eq!(known_answers.get(25, Part::Part2), Some("N.A."));
+        assert_eq!(known_answers.get(17, Part::Part2), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid_known_answer() {
+        assert!(KnownAnswers::from_str("1 3 42").is_err());
+        assert!(KnownAnswers::from_str("1 1").is_err());
+    }
+}
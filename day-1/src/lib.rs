@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution, parse_fragment, parse_lines};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (Vec<u32>, Vec<u32>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_separate_lists(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(lists: &(Vec<u32>, Vec<u32>)) -> Result<u32> {
+    let (mut left_list, mut right_list) = lists.clone();
+    left_list.sort();
+    right_list.sort();
+
+    Ok(left_list
+        .into_iter()
+        .zip(right_list)
+        .map(|(left_value, right_value)| left_value.abs_diff(right_value))
+        .sum())
+}
+
+pub fn part_2((left_list, right_list): &(Vec<u32>, Vec<u32>)) -> Result<u32> {
+    let mut number_counts: HashMap<u32, u32> = HashMap::new();
+    right_list
+        .iter()
+        .for_each(|right_value| *number_counts.entry(*right_value).or_default() += 1);
+
+    Ok(left_list
+        .iter()
+        .map(|left_value| left_value * *number_counts.entry(*left_value).or_default())
+        .sum())
+}
+
+pub fn parse_input_into_separate_lists(input: &str) -> Result<(Vec<u32>, Vec<u32>)> {
+    Ok(parse_lines(input, |line| {
+        let Some((left_value, right_value)) = line.split_once("   ") else {
+            return Err(anyhow!("Cannot split input into left and right values"));
+        };
+
+        Ok((
+            parse_fragment::<u32>(line, left_value)?,
+            parse_fragment::<u32>(line, right_value)?,
+        ))
+    })?
+    .into_iter()
+    .unzip())
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(1, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(1, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution};
+use grid::{Coord, Grid};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Grid<u32>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_grid(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(grid: &Grid<u32>) -> Result<usize> {
+    let trailheads = trailheads(grid);
+
+    let mut cache = HashMap::new();
+    Ok(trailheads
+        .into_iter()
+        .map(|trailhead| connected_peaks(trailhead, grid, &mut cache).len())
+        .sum())
+}
+
+pub fn part_2(grid: &Grid<u32>) -> Result<u32> {
+    let trailheads = trailheads(grid);
+
+    let mut cache = HashMap::new();
+    Ok(trailheads
+        .into_iter()
+        .map(|trailhead| count_paths_to_peaks(trailhead, grid, &mut cache))
+        .sum())
+}
+
+pub fn parse_input_into_grid(input: &str) -> Result<Grid<u32>> {
+    Grid::parse(input, |byte| {
+        if byte.is_ascii_digit() {
+            Ok((byte - b'0') as u32)
+        } else {
+            Err(anyhow!("Invalid byte: {}", byte as char))
+        }
+    })
+}
+
+fn trailheads(grid: &Grid<u32>) -> Vec<Coord> {
+    grid.positions(|&height| height == 0).collect()
+}
+
+/// Gets the neighbours that are exactly 1 higher than the coord.
+fn successors(coord: Coord, grid: &Grid<u32>) -> impl Iterator<Item = Coord> {
+    let height = grid[coord];
+
+    grid.neighbours_4(coord)
+        .filter(move |&neighbour| grid[neighbour] == height + 1)
+}
+
+fn connected_peaks(
+    coord: Coord,
+    grid: &Grid<u32>,
+    cache: &mut HashMap<Coord, HashSet<Coord>>,
+) -> HashSet<Coord> {
+    if cache.contains_key(&coord) {
+        return cache[&coord].clone();
+    }
+
+    if grid[coord] == 9 {
+        cache.entry(coord).or_default().insert(coord);
+        return cache[&coord].clone();
+    }
+
+    let peaks = successors(coord, grid)
+        .flat_map(|successor| connected_peaks(successor, grid, cache))
+        .collect::<HashSet<_>>();
+    cache.entry(coord).or_insert(peaks);
+    cache[&coord].clone()
+}
+
+fn count_paths_to_peaks(coord: Coord, grid: &Grid<u32>, cache: &mut HashMap<Coord, u32>) -> u32 {
+    if cache.contains_key(&coord) {
+        return cache[&coord];
+    }
+
+    if grid[coord] == 9 {
+        return *cache.entry(coord).or_insert(1);
+    }
+
+    let paths = successors(coord, grid)
+        .map(|successor| count_paths_to_peaks(successor, grid, cache))
+        .sum();
+    *cache.entry(coord).or_insert(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(10, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(10, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
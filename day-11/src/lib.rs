@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution, parse_fragment};
+
+pub struct Solver {
+    pub part_1_blinks: u8,
+    pub part_2_blinks: u8,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            part_1_blinks: 25,
+            part_2_blinks: 75,
+        }
+    }
+}
+
+impl Solution for Solver {
+    type Input<'a> = Vec<u64>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_stones(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input, self.part_1_blinks)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input, self.part_2_blinks)?.into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("part_1_blinks", self.part_1_blinks.to_string()),
+            ("part_2_blinks", self.part_2_blinks.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "part_1_blinks" => self.part_1_blinks = value.parse()?,
+            "part_2_blinks" => self.part_2_blinks = value.parse()?,
+            _ => return Err(anyhow!("Invalid parameter: {}", key)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part_1(stones: &[u64], blinks: u8) -> Result<usize> {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+        stones = blink_stones(stones);
+    }
+
+    Ok(stones.len())
+}
+
+pub fn part_2(stones: &[u64], blinks: u8) -> Result<u64> {
+    // Each stone expands independently of each other, so we can just blink them separately and then
+    // sum up the results.
+
+    let mut cache = HashMap::new();
+    Ok(stones
+        .iter()
+        .map(|stone| count_stones_after_blinks(*stone, blinks, &mut cache))
+        .sum())
+}
+
+pub fn parse_input_into_stones(input: &str) -> Result<Vec<u64>> {
+    input
+        .split_whitespace()
+        .map(|stone| parse_fragment(input, stone))
+        .collect()
+}
+
+fn blink(stone: u64) -> Vec<u64> {
+    match stone {
+        0 => vec![1],
+        d if d.ilog10() % 2 == 1 => {
+            let half_digits = d.ilog10().div_ceil(2);
+
+            vec![d / 10u64.pow(half_digits), d % 10u64.pow(half_digits)]
+        }
+        _ => vec![stone * 2024],
+    }
+}
+
+fn blink_stones(stones: Vec<u64>) -> Vec<u64> {
+    stones.into_iter().flat_map(blink).collect()
+}
+
+fn count_stones_after_blinks(stone: u64, blinks: u8, cache: &mut HashMap<(u64, u8), u64>) -> u64 {
+    if cache.contains_key(&(stone, blinks)) {
+        return cache[&(stone, blinks)];
+    }
+
+    if blinks == 0 {
+        return *cache.entry((stone, 0)).or_insert(1);
+    }
+
+    let count = blink(stone)
+        .into_iter()
+        .map(|stone| count_stones_after_blinks(stone, blinks - 1, cache))
+        .sum();
+    *cache.entry((stone, blinks)).or_insert(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, Puzzle, load_example, trim_newlines};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(11, "example")?.check(&mut Solver::default(), Part::Part1)
+    }
+
+    #[test]
+    fn example_1_after_6_blinks() -> Result<()> {
+        let solver = Solver {
+            part_1_blinks: 6,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            solver.solve(
+                Part::Part1,
+                trim_newlines(include_str!("../../examples/day-11/example.txt"))
+            )?,
+            Answer::Integer(22)
+        );
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn count_stones_after_blinks_matches_blink_stones(
+            stones in vec(0..1_000_000u64, 1..5),
+            blinks in 0..15u8,
+        ) {
+            let mut blinked_stones = stones.clone();
+            for _ in 0..blinks {
+                blinked_stones = blink_stones(blinked_stones);
+            }
+
+            let mut cache = HashMap::new();
+            let count = stones
+                .into_iter()
+                .map(|stone| count_stones_after_blinks(stone, blinks, &mut cache))
+                .sum::<u64>();
+
+            prop_assert_eq!(count, blinked_stones.len() as u64);
+        }
+    }
+}
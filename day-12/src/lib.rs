@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use cli::{Answer, Solution};
+use grid::{Coord, Grid};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Grid<u8>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Grid::parse(input, Ok)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(grid: &Grid<u8>) -> Result<usize> {
+    let regions = into_regions(grid.clone());
+
+    Ok(regions
+        .into_iter()
+        .map(|region| region.area() * region.parameter())
+        .sum())
+}
+
+pub fn part_2(grid: &Grid<u8>) -> Result<usize> {
+    let regions = into_regions(grid.clone());
+
+    Ok(regions
+        .into_iter()
+        .map(|region| region.area() * region.sides())
+        .sum())
+}
+
+fn into_regions(mut grid: Grid<u8>) -> Vec<Region> {
+    let mut regions = Vec::new();
+
+    for coord in grid.coords() {
+        let plant = grid[coord];
+        if plant == 0 {
+            continue;
+        }
+
+        let plots = flood_region(plant, coord, &mut grid);
+        regions.push(Region(plots));
+    }
+
+    regions
+}
+
+fn flood_region(plant: u8, coord: Coord, grid: &mut Grid<u8>) -> HashSet<Coord> {
+    if grid[coord] != plant {
+        return HashSet::new();
+    }
+
+    grid[coord] = 0;
+
+    let neighbours = grid.neighbours_4(coord).collect::<Vec<_>>();
+
+    let mut flooded_coords = neighbours
+        .into_iter()
+        .flat_map(|neighbour| flood_region(plant, neighbour, grid))
+        .collect::<HashSet<_>>();
+    flooded_coords.insert(coord);
+
+    flooded_coords
+}
+
+struct Region(HashSet<Coord>);
+
+impl Region {
+    fn area(&self) -> usize {
+        self.0.len()
+    }
+
+    fn parameter(&self) -> usize {
+        self.0
+            .iter()
+            .map(|&(row, col)| {
+                let mut fences = 4;
+
+                if row > 0 && self.0.contains(&(row - 1, col)) {
+                    fences -= 1;
+                }
+                if self.0.contains(&(row, col + 1)) {
+                    fences -= 1;
+                }
+                if self.0.contains(&(row + 1, col)) {
+                    fences -= 1;
+                }
+                if col > 0 && self.0.contains(&(row, col - 1)) {
+                    fences -= 1;
+                }
+
+                fences
+            })
+            .sum()
+    }
+
+    fn sides(&self) -> usize {
+        // The number of sides is equals to the number of corners. So look for:
+        //   ox Oo oO xo x  Ox xO  x
+        //   Oo ox xo oO Ox x   x xO
+        // where O is the plot in question, o is a neighbour in the region, x is outside the region.
+
+        self.0
+            .iter()
+            .map(|&(row, col)| {
+                let mut corners = 0;
+
+                let top = row > 0 && self.0.contains(&(row - 1, col));
+                let top_right = row > 0 && self.0.contains(&(row - 1, col + 1));
+                let right = self.0.contains(&(row, col + 1));
+                let bottom_right = self.0.contains(&(row + 1, col + 1));
+                let bottom = self.0.contains(&(row + 1, col));
+                let bottom_left = col > 0 && self.0.contains(&(row + 1, col - 1));
+                let left = col > 0 && self.0.contains(&(row, col - 1));
+                let top_left = row > 0 && col > 0 && self.0.contains(&(row - 1, col - 1));
+
+                if top && !top_right && right {
+                    corners += 1;
+                }
+                if right && !bottom_right && bottom {
+                    corners += 1;
+                }
+                if bottom && !bottom_left && left {
+                    corners += 1;
+                }
+                if left && !top_left && top {
+                    corners += 1;
+                }
+                if !top && !right {
+                    corners += 1;
+                }
+                if !right && !bottom {
+                    corners += 1;
+                }
+                if !bottom && !left {
+                    corners += 1;
+                }
+                if !left && !top {
+                    corners += 1;
+                }
+
+                corners
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1a() -> Result<()> {
+        load_example!(12, "a")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_1b() -> Result<()> {
+        load_example!(12, "b")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_1c() -> Result<()> {
+        load_example!(12, "c")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2a() -> Result<()> {
+        load_example!(12, "a")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_2b() -> Result<()> {
+        load_example!(12, "b")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_2c() -> Result<()> {
+        load_example!(12, "c")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_2d() -> Result<()> {
+        load_example!(12, "d")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_2e() -> Result<()> {
+        load_example!(12, "e")?.check(&mut Solver, Part::Part2)
+    }
+}
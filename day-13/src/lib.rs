@@ -0,0 +1,145 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use nalgebra::{matrix, vector};
+
+use cli::{Answer, ParseError, Solution, parse_fragment, parse_section};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<ClawMachine>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        input
+            .split_terminator("\n\n")
+            .map(|block| parse_section(input, block, ClawMachine::from_str))
+            .collect()
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(claw_machines: &[ClawMachine]) -> Result<u64> {
+    Ok(claw_machines
+        .iter()
+        .filter_map(ClawMachine::solve)
+        .map(|solution| solution.0 * 3 + solution.1)
+        .sum())
+}
+
+pub fn part_2(claw_machines: &[ClawMachine]) -> Result<u64> {
+    Ok(claw_machines
+        .iter()
+        .map(|claw_machine| ClawMachine {
+            prize: (
+                10000000000000 + claw_machine.prize.0,
+                10000000000000 + claw_machine.prize.1,
+            ),
+            ..*claw_machine
+        })
+        .filter_map(|claw_machine| claw_machine.solve())
+        .map(|solution| solution.0 * 3 + solution.1)
+        .sum())
+}
+
+/// (x, y)
+pub type Vector2 = (u64, u64);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ClawMachine {
+    pub a: Vector2,
+    pub b: Vector2,
+    pub prize: Vector2,
+}
+
+impl FromStr for ClawMachine {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+        if lines.len() != 3 {
+            return Err(anyhow!("Invalid claw machine, expected 3 lines"));
+        }
+
+        let Some((a_x, a_y)) = lines[0]
+            .strip_prefix("Button A: X+")
+            .and_then(|s| s.split_once(", Y+"))
+        else {
+            return Err(
+                ParseError::at(s, lines[0], "Cannot split input into button A X and Y").into(),
+            );
+        };
+        let a = (parse_fragment(s, a_x)?, parse_fragment(s, a_y)?);
+
+        let Some((b_x, b_y)) = lines[1]
+            .strip_prefix("Button B: X+")
+            .and_then(|s| s.split_once(", Y+"))
+        else {
+            return Err(
+                ParseError::at(s, lines[1], "Cannot split input into button B X and Y").into(),
+            );
+        };
+        let b = (parse_fragment(s, b_x)?, parse_fragment(s, b_y)?);
+
+        let Some((prize_x, prize_y)) = lines[2]
+            .strip_prefix("Prize: X=")
+            .and_then(|s| s.split_once(", Y="))
+        else {
+            return Err(
+                ParseError::at(s, lines[2], "Cannot split input into prize X and Y").into(),
+            );
+        };
+        let prize = (parse_fragment(s, prize_x)?, parse_fragment(s, prize_y)?);
+
+        Ok(Self { a, b, prize })
+    }
+}
+
+impl ClawMachine {
+    /// Solves number of A and B presses to reach the prize.
+    pub fn solve(&self) -> Option<Vector2> {
+        // Solve:
+        // (i)  a.0 * x + b.0 * y = prize.0
+        // (ii) a.1 * x + b.1 * y = prize.1
+
+        let coefficients = matrix![
+            self.a.0 as f64, self.b.0 as f64;
+            self.a.1 as f64, self.b.1 as f64;
+        ];
+        let constants = vector![self.prize.0 as f64, self.prize.1 as f64];
+        let solution = coefficients.lu().solve(&constants)?;
+
+        if solution[0].is_sign_negative() || solution[1].is_sign_negative() {
+            return None;
+        }
+        let x = solution[0].round() as u64;
+        let y = solution[1].round() as u64;
+
+        if x * self.a.0 + y * self.b.0 == self.prize.0
+            && x * self.a.1 + y * self.b.1 == self.prize.1
+        {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(13, "example")?.check(&mut Solver, Part::Part1)
+    }
+}
@@ -0,0 +1,29 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use cli::{Solution, trim_newlines};
+use day_13::{ClawMachine, Solver, part_1};
+
+#[test]
+fn solve_claw_machine() -> Result<()> {
+    let claw_machine =
+        ClawMachine::from_str("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400")?;
+
+    assert_eq!(claw_machine.prize, (8400, 5400));
+    assert_eq!(claw_machine.solve(), Some((80, 40)));
+
+    Ok(())
+}
+
+#[test]
+fn parse_and_solve_example() -> Result<()> {
+    let example = include_str!("../../examples/day-13/example.txt");
+
+    let claw_machines = Solver.parse(trim_newlines(example))?;
+
+    assert_eq!(claw_machines.len(), 4);
+    assert_eq!(part_1(&claw_machines)?, 480);
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, ParseError, Solution, parse_fragment, parse_grid_length, parse_lines};
+
+pub struct Solver {
+    pub grid_size: GridSize,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            grid_size: (101, 103),
+        }
+    }
+}
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Robot>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_lines(input, Robot::from_str)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input, self.grid_size)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input, self.grid_size)?.into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("width", self.grid_size.0.to_string()),
+            ("height", self.grid_size.1.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "width" => self.grid_size.0 = parse_grid_length(value)?,
+            "height" => self.grid_size.1 = parse_grid_length(value)?,
+            _ => return Err(anyhow!("Invalid parameter: {}", key)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part_1(robots: &[Robot], grid_size: GridSize) -> Result<u32> {
+    safety_factor(robots, grid_size)
+}
+
+pub fn part_2(robots: &[Robot], grid_size: GridSize) -> Result<u32> {
+    let mut time = 1;
+    loop {
+        let pattern = robots
+            .iter()
+            .map(|robot| robot.pos(time, grid_size))
+            .collect::<HashSet<_>>();
+
+        if contains_corner(&pattern) {
+            break;
+        }
+
+        time += 1;
+    }
+
+    Ok(time)
+}
+
+/// (width, height)
+pub type GridSize = (usize, usize);
+
+fn safety_factor(robots: &[Robot], grid_size: GridSize) -> Result<u32> {
+    let mid_x = grid_size.0 / 2;
+    let mid_y = grid_size.1 / 2;
+
+    let low_x = 0..mid_x;
+    let high_x = mid_x + 1..grid_size.0;
+    let low_y = 0..mid_y;
+    let high_y = mid_y + 1..grid_size.1;
+
+    Ok(robots
+        .iter()
+        .map(|robot| robot.pos(100, grid_size))
+        .fold([0; 4], |acc, pos| {
+            // acc: [top_left, top_right, bottom_left, bottom_right]
+
+            match (pos.0 as usize, pos.1 as usize) {
+                (x, y) if low_x.contains(&x) && low_y.contains(&y) => {
+                    [acc[0] + 1, acc[1], acc[2], acc[3]]
+                }
+                (x, y) if high_x.contains(&x) && low_y.contains(&y) => {
+                    [acc[0], acc[1] + 1, acc[2], acc[3]]
+                }
+                (x, y) if low_x.contains(&x) && high_y.contains(&y) => {
+                    [acc[0], acc[1], acc[2] + 1, acc[3]]
+                }
+                (x, y) if high_x.contains(&x) && high_y.contains(&y) => {
+                    [acc[0], acc[1], acc[2], acc[3] + 1]
+                }
+                _ => acc,
+            }
+        })
+        .into_iter()
+        .product())
+}
+
+pub type Vector2 = (i32, i32);
+
+pub struct Robot {
+    pub initial_pos: Vector2,
+    pub velocity: Vector2,
+}
+
+impl FromStr for Robot {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((pos, velocity)) = s.strip_prefix("p=").and_then(|s| s.split_once(" v=")) else {
+            return Err(anyhow!("Cannot split input into pos and velocity"));
+        };
+
+        let Some((x, y)) = pos.split_once(",") else {
+            return Err(ParseError::at(s, pos, "Cannot split input into x and y").into());
+        };
+        let pos = (parse_fragment(s, x)?, parse_fragment(s, y)?);
+
+        let Some((x, y)) = velocity.split_once(",") else {
+            return Err(ParseError::at(s, velocity, "Cannot split input into x and y").into());
+        };
+        let velocity = (parse_fragment(s, x)?, parse_fragment(s, y)?);
+
+        Ok(Self {
+            initial_pos: pos,
+            velocity,
+        })
+    }
+}
+
+impl Robot {
+    /// Gets the position after some time, wrapping around the edges of the grid.
+    pub fn pos(&self, time: u32, grid_size: GridSize) -> Vector2 {
+        let mut x = (self.initial_pos.0 + time as i32 * self.velocity.0) % grid_size.0 as i32;
+        if x < 0 {
+            x += grid_size.0 as i32;
+        }
+        let mut y = (self.initial_pos.1 + time as i32 * self.velocity.1) % grid_size.1 as i32;
+        if y < 0 {
+            y += grid_size.1 as i32;
+        }
+
+        (x, y)
+    }
+}
+
+/// Finds a top-left corner formed by 5-unit-long straight lines.
+fn contains_corner(pattern: &HashSet<Vector2>) -> bool {
+    pattern.iter().any(|&(x, y)| {
+        [
+            (x + 1, y),
+            (x + 2, y),
+            (x + 3, y),
+            (x + 4, y),
+            (x, y + 1),
+            (x, y + 2),
+            (x, y + 3),
+            (x, y + 4),
+        ]
+        .into_iter()
+        .all(|pos| pattern.contains(&pos))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(14, "example")?.check(&mut Solver::default(), Part::Part1)
+    }
+
+    #[test]
+    fn reject_empty_grid() {
+        let mut solver = Solver::default();
+
+        assert!(Solution::set_param(&mut solver, "width", "0").is_err());
+        assert!(Solution::set_param(&mut solver, "height", "0").is_err());
+        assert_eq!(solver.grid_size, (101, 103));
+    }
+}
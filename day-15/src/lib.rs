@@ -0,0 +1,221 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution, parse_fragment, parse_section};
+use grid::{Coord, Direction, Grid};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (Warehouse, Vec<Direction>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_grid_and_pushes(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1((warehouse, pushes): &(Warehouse, Vec<Direction>)) -> Result<usize> {
+    let mut warehouse = warehouse.clone();
+
+    for &push in pushes {
+        warehouse.push(push);
+    }
+
+    Ok(warehouse.box_gps_sum())
+}
+
+pub fn part_2((warehouse, pushes): &(Warehouse, Vec<Direction>)) -> Result<usize> {
+    let mut warehouse = warehouse.clone().into_wide_grid()?;
+
+    for &push in pushes {
+        warehouse.push(push);
+    }
+
+    Ok(warehouse.box_gps_sum())
+}
+
+#[derive(Clone)]
+pub struct Warehouse {
+    layout: Grid<u8>,
+    robot: Coord,
+    is_wide: bool,
+}
+
+impl FromStr for Warehouse {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let layout = Grid::parse(s, Ok)?;
+        let robot = layout.find(&b'@').ok_or(anyhow!("Cannot find robot"))?;
+
+        Ok(Self {
+            layout,
+            robot,
+            is_wide: false,
+        })
+    }
+}
+
+impl Warehouse {
+    /// Moves the robot one step, pushing any boxes in the way unless they are blocked by a wall.
+    pub fn push(&mut self, direction: Direction) {
+        if self.is_wide && direction.is_vertical() {
+            self.push_for_wide_grid(direction);
+        } else {
+            self.push_in_line(direction);
+        }
+    }
+
+    /// Pushes the robot and the line of boxes in front of it into the first space, if any.
+    fn push_in_line(&mut self, direction: Direction) {
+        let line = self
+            .layout
+            .ray(self.robot, direction.delta())
+            .collect::<Vec<_>>();
+
+        let Some(space) = line
+            .iter()
+            .position(|&coord| matches!(self.layout[coord], b'#' | b'.'))
+        else {
+            return;
+        };
+        if self.layout[line[space]] == b'#' {
+            return;
+        }
+
+        for index in (1..=space).rev() {
+            self.layout.swap(line[index], line[index - 1]);
+        }
+        self.robot = line[1];
+    }
+
+    pub fn box_gps_sum(&self) -> usize {
+        self.layout
+            .iter()
+            .filter_map(|((row, col), byte)| match (self.is_wide, *byte) {
+                (false, b'O') | (true, b'[') => Some(row * 100 + col),
+                _ => None,
+            })
+            .sum()
+    }
+
+    pub fn into_wide_grid(self) -> Result<Self> {
+        if self.is_wide {
+            return Ok(self);
+        }
+
+        let cells = self
+            .layout
+            .iter()
+            .flat_map(|(_, &byte)| match byte {
+                b'O' => [b'[', b']'],
+                b'@' => [b'@', b'.'],
+                _ => [byte, byte],
+            })
+            .collect();
+        let layout = Grid::from_cells(self.layout.width() * 2, cells)?;
+
+        Ok(Self {
+            layout,
+            robot: (self.robot.0, self.robot.1 * 2),
+            is_wide: true,
+        })
+    }
+
+    fn push_for_wide_grid(&mut self, direction: Direction) {
+        if self.can_push_from_coord(self.robot, direction)
+            && let Some(next) = self.layout.step(self.robot, direction.delta())
+        {
+            self.push_from_coord(self.robot, direction);
+            self.robot = next;
+        }
+    }
+
+    fn can_push_from_coord(&self, coord: Coord, direction: Direction) -> bool {
+        let Some(next) = self.layout.step(coord, direction.delta()) else {
+            return false;
+        };
+
+        match self.layout[next] {
+            b'.' => true,
+            b'[' => {
+                self.can_push_from_coord(next, direction)
+                    && self.can_push_from_coord((next.0, next.1 + 1), direction)
+            }
+            b']' => {
+                self.can_push_from_coord(next, direction)
+                    && self.can_push_from_coord((next.0, next.1 - 1), direction)
+            }
+            _ => false,
+        }
+    }
+
+    fn push_from_coord(&mut self, coord: Coord, direction: Direction) {
+        let Some(next) = self.layout.step(coord, direction.delta()) else {
+            return;
+        };
+
+        match self.layout[next] {
+            b'[' => {
+                self.push_from_coord(next, direction);
+                self.push_from_coord((next.0, next.1 + 1), direction);
+            }
+            b']' => {
+                self.push_from_coord(next, direction);
+                self.push_from_coord((next.0, next.1 - 1), direction);
+            }
+            _ => (),
+        }
+
+        self.layout.swap(coord, next);
+    }
+}
+
+pub fn parse_input_into_grid_and_pushes(input: &str) -> Result<(Warehouse, Vec<Direction>)> {
+    let Some((grid, pushes)) = input.split_once("\n\n") else {
+        return Err(anyhow!("Cannot split input into grid and pushes"));
+    };
+
+    let warehouse = parse_section(input, grid, Warehouse::from_str)?;
+
+    // Match each push as its own slice of the input, so that errors can be located.
+    let pushes = parse_section(input, pushes, |pushes| {
+        pushes
+            .matches(|push| push != '\n')
+            .map(|push| parse_fragment(pushes, push))
+            .collect()
+    })?;
+
+    Ok((warehouse, pushes))
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1a() -> Result<()> {
+        load_example!(15, "a")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_1b() -> Result<()> {
+        load_example!(15, "b")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(15, "a")?.check(&mut Solver, Part::Part2)
+    }
+}
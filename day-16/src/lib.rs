@@ -0,0 +1,146 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Result, anyhow};
+use pathfinding::prelude::{astar, astar_bag};
+
+use cli::{Answer, Solution};
+use grid::{Coord, Direction, Grid};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Maze;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Maze::from_str(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(maze: &Maze) -> Result<u32> {
+    let start = (maze.start, Direction::E);
+
+    astar(
+        &start,
+        |node| successors(node, &maze.layout),
+        |_| 0,
+        |node| node.0 == maze.end,
+    )
+    .map(|shortest_path| shortest_path.1)
+    .ok_or(anyhow!("Cannot find shortest path"))
+}
+
+pub fn part_2(maze: &Maze) -> Result<usize> {
+    let start = (maze.start, Direction::E);
+
+    let Some((shortest_paths, _)) = astar_bag(
+        &start,
+        |node| successors(node, &maze.layout),
+        |_| 0,
+        |node| node.0 == maze.end,
+    ) else {
+        return Err(anyhow!("Cannot find shortest path"));
+    };
+
+    Ok(shortest_paths
+        .into_iter()
+        .flatten()
+        .map(|node| node.0)
+        .collect::<HashSet<_>>()
+        .len())
+}
+
+pub struct Maze {
+    pub layout: Grid<u8>,
+    pub start: Coord,
+    pub end: Coord,
+}
+
+impl FromStr for Maze {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut layout = Grid::parse(s, Ok)?;
+        let start = layout.find(&b'S').ok_or(anyhow!("Cannot find start"))?;
+        let end = layout.find(&b'E').ok_or(anyhow!("Cannot find end"))?;
+        layout[start] = b'.';
+        layout[end] = b'.';
+
+        Ok(Self { layout, start, end })
+    }
+}
+
+type Node = (Coord, Direction);
+
+fn successors(node: &Node, grid: &Grid<u8>) -> Vec<(Node, u32)> {
+    let mut successors = Vec::new();
+
+    let &(coord, direction) = node;
+    // Move forward. The outside of the maze counts as walls, so it need not have a border.
+    if let Some(next) = grid
+        .step(coord, direction.delta())
+        .filter(|&next| grid[next] == b'.')
+    {
+        successors.push(((next, direction), 1));
+    }
+
+    // Turn 90 degrees.
+    successors.push(((coord, direction.turn_left()), 1000));
+    successors.push(((coord, direction.turn_right()), 1000));
+
+    successors
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1a() -> Result<()> {
+        load_example!(16, "a")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_1b() -> Result<()> {
+        load_example!(16, "b")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_1c() -> Result<()> {
+        load_example!(16, "c")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_1d() -> Result<()> {
+        load_example!(16, "d")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2a() -> Result<()> {
+        load_example!(16, "a")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_2b() -> Result<()> {
+        load_example!(16, "b")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_2c() -> Result<()> {
+        load_example!(16, "c")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_2d() -> Result<()> {
+        load_example!(16, "d")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,196 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::ParseError;
+
+use crate::{Instruction, Opcode};
+
+/// Assembles mnemonic source, as printed by the disassembler, into a program.
+///
+/// Each line holds an instruction, such as `adv 3` or `out B`, optionally preceded by a label such
+/// as `start:`, and followed by a `;` comment. The operand of bxc, which is ignored, may be left
+/// out, and jnz takes either an instruction pointer or a label. Errors are located at the line or
+/// operand at fault.
+pub fn assemble(source: &str) -> Result<Vec<u8>> {
+    // The label definitions, and the instructions with their still unresolved operands.
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    for line in source.lines() {
+        let mut code = line.split(';').next().unwrap_or_default().trim();
+
+        if let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(ParseError::at(source, label, "Invalid label").into());
+            }
+            if labels.insert(label, statements.len() * 2).is_some() {
+                return Err(ParseError::at(source, label, "Duplicate label").into());
+            }
+
+            code = rest.trim();
+        }
+        if code.is_empty() {
+            continue;
+        }
+
+        let words = code.split_whitespace().collect::<Vec<_>>();
+        let opcode =
+            Opcode::from_str(words[0]).map_err(|error| ParseError::at(source, words[0], error))?;
+        let operand = match (opcode, words.as_slice()) {
+            (Opcode::Bxc, [_]) => None,
+            (_, [_, operand]) => Some(*operand),
+            _ => {
+                return Err(ParseError::at(
+                    source,
+                    code,
+                    format!("Invalid {}, expected an operand", opcode.mnemonic()),
+                )
+                .into());
+            }
+        };
+
+        statements.push((opcode, operand));
+    }
+
+    let mut program = Vec::new();
+    for (opcode, operand) in statements {
+        let value = match operand {
+            Some(operand) => operand_value(opcode, operand, &labels)
+                .map_err(|error| ParseError::at(source, operand, error))?,
+            None => 0,
+        };
+
+        program.extend([opcode as u8, value]);
+    }
+
+    Ok(program)
+}
+
+/// Formats the program as comma-separated values, as in the puzzle input.
+pub fn format_program(program: &[u8]) -> String {
+    program
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn is_label(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn operand_value(opcode: Opcode, operand: &str, labels: &HashMap<&str, usize>) -> Result<u8> {
+    let value = match (opcode, operand) {
+        (Opcode::Jnz, label) if is_label(label) => {
+            let ip = *labels
+                .get(label)
+                .ok_or(anyhow!("Invalid label: {}", label))?;
+
+            // The operand is 3 bits, so only the first 4 instructions can be jumped to.
+            u8::try_from(ip).ok().filter(|&ip| ip < 8).ok_or(anyhow!(
+                "Invalid jump target, {} is at {}",
+                label,
+                ip
+            ))?
+        }
+        (_, "A") if opcode.takes_combo_operand() => 4,
+        (_, "B") if opcode.takes_combo_operand() => 5,
+        (_, "C") if opcode.takes_combo_operand() => 6,
+        _ => operand
+            .parse()
+            .map_err(|_| anyhow!("Invalid operand: {}", operand))?,
+    };
+
+    if value >= 8 {
+        return Err(anyhow!("Invalid operand: {}", operand));
+    }
+    // Combo operand 7 is reserved.
+    Instruction {
+        opcode,
+        operand: value,
+    }
+    .operand_source()?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use crate::disassemble_with_labels;
+
+    use super::*;
+
+    #[test]
+    fn assemble_source() -> Result<()> {
+        let source = "\
+; The part 2 example
+start:
+    adv 3
+    out A  ; the low 3 bits of A
+    jnz start
+";
+        assert_eq!(format_program(&assemble(source)?), "0,3,5,4,3,0");
+        assert_eq!(
+            assemble("bst A\nbxl 7\nbxc\nl: cdv B\njnz l\nbxc 1\njnz 2")?,
+            [2, 4, 1, 7, 4, 0, 7, 5, 3, 6, 4, 1, 3, 2]
+        );
+
+        let error = assemble("adv 3\nout D")
+            .unwrap_err()
+            .downcast::<ParseError>()?;
+        assert_eq!((error.line(), error.column()), (2, 5));
+
+        assert!(assemble("adv 7").is_err());
+        assert!(assemble("bxl 8").is_err());
+        assert!(assemble("bxl A").is_err());
+        assert!(assemble("jnz end").is_err());
+        assert!(assemble("a:\na:").is_err());
+        assert!(assemble("nop 0").is_err());
+        assert!(assemble("out").is_err());
+        assert!(assemble("out A B").is_err());
+        assert!(assemble("bxc 8").is_err());
+        assert!(assemble(&("bxc\n".repeat(4) + "end: jnz end")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_with_disassembler() -> Result<()> {
+        let program = [2, 4, 1, 3, 7, 5, 0, 3, 1, 5, 4, 4, 5, 5, 3, 0];
+
+        let source = disassemble_with_labels(&program)?;
+        assert!(source.starts_with("l0:\n    bst A\n"));
+        assert!(source.ends_with("    jnz l0\n"));
+        assert_eq!(assemble(&source)?, program);
+        assert_eq!(disassemble_with_labels(&assemble(&source)?)?, source);
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn disassembled_program_assembles_to_itself(
+            pairs in vec((0..8u8, 0..8u8), 0..16)
+        ) {
+            // Combo operand 7 is reserved, so it does not disassemble.
+            let program = pairs
+                .into_iter()
+                .map(|(opcode, operand)| {
+                    if Opcode::try_from(opcode).unwrap().takes_combo_operand() {
+                        [opcode, operand % 7]
+                    } else {
+                        [opcode, operand]
+                    }
+                })
+                .collect::<Vec<_>>()
+                .concat();
+
+            let source = disassemble_with_labels(&program).unwrap();
+            prop_assert_eq!(assemble(&source).unwrap(), program);
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt::{self, Display, Formatter};
+
+use anyhow::{Result, anyhow};
+
+use crate::{Computer, Instruction, Opcode, Register, Step};
+
+/// A condition that stops the debugger when it continues.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// Stops before the instruction at the instruction pointer.
+    Ip(usize),
+
+    /// Stops once the output has grown to the length.
+    OutputLen(usize),
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ip(ip) => write!(f, "ip {ip}"),
+            Self::OutputLen(len) => write!(f, "output {len}"),
+        }
+    }
+}
+
+/// An interactive debugger, which runs a computer one command at a time.
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: Vec<Breakpoint>,
+}
+
+impl Debugger {
+    pub const HELP: &str = "\
+step [<COUNT>]         Executes 1 or more instructions (s)
+continue               Runs until a breakpoint or the end of the program (c)
+break ip <IP>          Stops before the instruction at the instruction pointer (b)
+break output <LEN>     Stops once the output has grown to the length (b)
+delete <INDEX>         Removes a breakpoint (d)
+breakpoints            Lists the breakpoints
+set a|b|c|ip <VALUE>   Sets a register or the instruction pointer
+print                  Shows the state of the computer (p)
+quit                   Exits the debugger (q)";
+
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            computer,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    /// Executes a command, e.g. `step 3` or `break ip 4`, and gets the text to show for it.
+    pub fn execute(&mut self, command: &str) -> Result<String> {
+        let words = command.split_whitespace().collect::<Vec<_>>();
+
+        match words.as_slice() {
+            ["step" | "s"] => self.step(1),
+            ["step" | "s", count] => self.step(parse_number(count)?),
+            ["continue" | "c"] => self.proceed(),
+            ["break" | "b", "ip", ip] => self.add_breakpoint(Breakpoint::Ip(parse_number(ip)?)),
+            ["break" | "b", "output", len] => {
+                self.add_breakpoint(Breakpoint::OutputLen(parse_number(len)?))
+            }
+            ["delete" | "d", index] => self.delete_breakpoint(parse_number(index)?),
+            ["breakpoints"] => Ok(self.list_breakpoints()),
+            ["set", name, value] => self.set(name, value),
+            ["print" | "p"] => Ok(self.state()),
+            ["help" | "h"] => Ok(String::from(Self::HELP)),
+            _ => Err(anyhow!("Invalid command: {}", command)),
+        }
+    }
+
+    /// Executes up to count instructions, stopping early if the program halts.
+    fn step(&mut self, count: usize) -> Result<String> {
+        let mut lines = Vec::new();
+        for _ in 0..count {
+            if self.computer.is_halted() {
+                break;
+            }
+
+            lines.push(show_step(&self.computer.step_traced()?));
+        }
+        lines.push(self.state());
+
+        Ok(lines.join("\n"))
+    }
+
+    /// Executes instructions until a breakpoint is hit or the program halts.
+    ///
+    /// At least one instruction is executed, so that continuing from a breakpoint moves past it.
+    fn proceed(&mut self) -> Result<String> {
+        let mut hit = None;
+        while hit.is_none() && !self.computer.is_halted() {
+            let step = self.computer.step_traced()?;
+
+            hit = self
+                .breakpoints
+                .iter()
+                .position(|breakpoint| match breakpoint {
+                    Breakpoint::Ip(ip) => self.computer.ip() == *ip,
+                    Breakpoint::OutputLen(len) => {
+                        step.output.is_some() && self.computer.output.len() == *len
+                    }
+                });
+        }
+
+        Ok(match hit {
+            Some(index) => format!(
+                "Breakpoint {index} ({})\n{}",
+                self.breakpoints[index],
+                self.state()
+            ),
+            None => self.state(),
+        })
+    }
+
+    fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> Result<String> {
+        self.breakpoints.push(breakpoint);
+
+        Ok(format!(
+            "Breakpoint {} ({breakpoint})",
+            self.breakpoints.len() - 1
+        ))
+    }
+
+    fn delete_breakpoint(&mut self, index: usize) -> Result<String> {
+        if index >= self.breakpoints.len() {
+            return Err(anyhow!("Invalid breakpoint: {}", index));
+        }
+
+        Ok(format!("Deleted ({})", self.breakpoints.remove(index)))
+    }
+
+    fn list_breakpoints(&self) -> String {
+        self.breakpoints
+            .iter()
+            .enumerate()
+            .map(|(index, breakpoint)| format!("{index}: {breakpoint}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<String> {
+        match name {
+            "a" => self.computer.a = parse_register(value)?,
+            "b" => self.computer.b = parse_register(value)?,
+            "c" => self.computer.c = parse_register(value)?,
+            "ip" => self.computer.jump(parse_number(value)?),
+            _ => return Err(anyhow!("Invalid register: {}", name)),
+        }
+
+        Ok(self.state())
+    }
+
+    /// Shows the next instruction, the registers and the output so far.
+    fn state(&self) -> String {
+        let computer = &self.computer;
+
+        let next = if computer.is_halted() {
+            String::from("halted")
+        } else {
+            computer
+                .program
+                .get(computer.ip() + 1)
+                .zip(Opcode::try_from(computer.program[computer.ip()]).ok())
+                .map_or(String::from("invalid"), |(&operand, opcode)| {
+                    Instruction { opcode, operand }.to_string()
+                })
+        };
+
+        let output = computer
+            .output
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            "ip {}: {next} | {} | output [{output}]",
+            computer.ip(),
+            computer.registers()
+        )
+    }
+}
+
+fn show_step(step: &Step) -> String {
+    let output = step
+        .output
+        .map_or(String::new(), |value| format!(" | out {value}"));
+
+    format!(
+        "ip {}: {} | {}{output}",
+        step.ip, step.instruction, step.after
+    )
+}
+
+fn parse_number(s: &str) -> Result<usize> {
+    s.parse().map_err(|_| anyhow!("Invalid number: {}", s))
+}
+
+fn parse_register(s: &str) -> Result<Register> {
+    s.parse()
+        .map_err(|_| anyhow!("Invalid register value: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../examples/day-17/part-1.txt");
+
+    #[test]
+    fn stop_at_breakpoints() -> Result<()> {
+        let mut debugger = Debugger::new(Computer::from_str(trim_newlines(EXAMPLE))?);
+
+        debugger.execute("break output 2")?;
+        assert!(debugger.execute("continue")?.starts_with("Breakpoint 0"));
+        assert_eq!(debugger.computer().output, [4, 6]);
+
+        debugger.execute("b ip 0")?;
+        debugger.execute("c")?;
+        assert_eq!(debugger.computer().ip(), 0);
+        assert_eq!(debugger.computer().output, [4, 6]);
+        debugger.execute("c")?;
+        assert_eq!(debugger.computer().ip(), 0);
+        assert_eq!(debugger.computer().output, [4, 6, 3]);
+
+        debugger.execute("delete 1")?;
+        debugger.execute("d 0")?;
+        assert!(debugger.execute("delete 0").is_err());
+        debugger.execute("c")?;
+        assert!(debugger.computer().is_halted());
+        assert_eq!(debugger.computer().output, [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_and_edit_registers() -> Result<()> {
+        let mut debugger = Debugger::new(Computer::from_str(trim_newlines(EXAMPLE))?);
+
+        debugger.execute("set a 8")?;
+        assert_eq!(
+            debugger.execute("step 2")?,
+            "ip 0: adv 1 | A=4 B=0 C=0\n\
+             ip 2: out A | A=4 B=0 C=0 | out 4\n\
+             ip 4: jnz 0 | A=4 B=0 C=0 | output [4]"
+        );
+
+        debugger.execute("set ip 6")?;
+        assert!(
+            debugger
+                .execute("s")?
+                .ends_with("ip 6: halted | A=4 B=0 C=0 | output [4]")
+        );
+        assert!(debugger.execute("set d 1").is_err());
+        assert!(debugger.execute("jump 0").is_err());
+
+        Ok(())
+    }
+}
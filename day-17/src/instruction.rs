@@ -0,0 +1,308 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use anyhow::{Result, anyhow};
+
+/// The 8 opcodes of the 3-bit computer, by value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    const ALL: [Self; 8] = [
+        Self::Adv,
+        Self::Bxl,
+        Self::Bst,
+        Self::Jnz,
+        Self::Bxc,
+        Self::Out,
+        Self::Bdv,
+        Self::Cdv,
+    ];
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Adv => "adv",
+            Self::Bxl => "bxl",
+            Self::Bst => "bst",
+            Self::Jnz => "jnz",
+            Self::Bxc => "bxc",
+            Self::Out => "out",
+            Self::Bdv => "bdv",
+            Self::Cdv => "cdv",
+        }
+    }
+
+    /// Checks whether the operand is a combo operand, rather than a literal one.
+    pub fn takes_combo_operand(self) -> bool {
+        matches!(
+            self,
+            Self::Adv | Self::Bst | Self::Out | Self::Bdv | Self::Cdv
+        )
+    }
+}
+
+impl TryFrom<u8> for Opcode {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .get(value as usize)
+            .copied()
+            .ok_or(anyhow!("Invalid opcode: {}", value))
+    }
+}
+
+impl FromStr for Opcode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == s)
+            .ok_or(anyhow!("Invalid mnemonic: {}", s))
+    }
+}
+
+/// An opcode and its operand, as a pair of values in the program.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    /// Gets the operand as source, with combo operands 4 to 6 resolved to the registers.
+    pub fn operand_source(&self) -> Result<String> {
+        if !self.opcode.takes_combo_operand() {
+            return Ok(self.operand.to_string());
+        }
+
+        match self.operand {
+            0..=3 => Ok(self.operand.to_string()),
+            4 => Ok(String::from("A")),
+            5 => Ok(String::from("B")),
+            6 => Ok(String::from("C")),
+            _ => Err(anyhow!("Invalid combo operand: {}", self.operand)),
+        }
+    }
+}
+
+/// Shows the instruction as a mnemonic and its operand, e.g. `adv 3` or `out B`. The operand of
+/// bxc is ignored by the computer, so it is not shown.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.opcode == Opcode::Bxc {
+            return write!(f, "{}", self.opcode.mnemonic());
+        }
+
+        let operand = self
+            .operand_source()
+            .unwrap_or_else(|_| self.operand.to_string());
+
+        write!(f, "{} {}", self.opcode.mnemonic(), operand)
+    }
+}
+
+/// Decodes the program into instructions.
+pub fn decode(program: &[u8]) -> Result<Vec<Instruction>> {
+    if !program.len().is_multiple_of(2) {
+        return Err(anyhow!("Cannot get operand"));
+    }
+
+    program
+        .chunks(2)
+        .map(|pair| {
+            let instruction = Instruction {
+                opcode: Opcode::try_from(pair[0])?,
+                operand: pair[1],
+            };
+            instruction.operand_source()?;
+
+            Ok(instruction)
+        })
+        .collect()
+}
+
+/// Disassembles the program into mnemonics, one instruction per line.
+pub fn disassemble(program: &[u8]) -> Result<String> {
+    Ok(decode(program)?
+        .into_iter()
+        .map(|instruction| format!("{instruction}\n"))
+        .collect())
+}
+
+/// Disassembles the program like [disassemble], but with a label before each jump target, so that
+/// the source can be edited and assembled again.
+///
+/// The operand of bxc is shown if it is not 0, so that the round trip gives the same program.
+pub fn disassemble_with_labels(program: &[u8]) -> Result<String> {
+    let instructions = decode(program)?;
+
+    // Jumps to odd or out of range instruction pointers are left as numbers.
+    let targets = instructions
+        .iter()
+        .filter(|instruction| instruction.opcode == Opcode::Jnz)
+        .map(|instruction| instruction.operand as usize)
+        .filter(|&ip| ip.is_multiple_of(2) && ip < program.len())
+        .collect::<BTreeSet<_>>();
+
+    let mut source = String::new();
+    for (index, instruction) in instructions.into_iter().enumerate() {
+        if targets.contains(&(index * 2)) {
+            source.push_str(&format!("l{}:\n", index * 2));
+        }
+
+        if instruction.opcode == Opcode::Jnz && targets.contains(&(instruction.operand as usize)) {
+            source.push_str(&format!("    jnz l{}\n", instruction.operand));
+        } else if instruction.opcode == Opcode::Bxc && instruction.operand != 0 {
+            source.push_str(&format!("    bxc {}\n", instruction.operand));
+        } else {
+            source.push_str(&format!("    {instruction}\n"));
+        }
+    }
+
+    Ok(source)
+}
+
+/// The language of the pseudo-code for a program.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Language {
+    Rust,
+    C,
+}
+
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" => Ok(Self::Rust),
+            "c" => Ok(Self::C),
+            _ => Err(anyhow!("Invalid language: {}", s)),
+        }
+    }
+}
+
+/// Renders the program as equivalent pseudo-code, with a variable for each register.
+///
+/// A program that ends with `jnz 0`, and has no other jumps, becomes a loop that runs until A is 0.
+pub fn pseudo_code(program: &[u8], language: Language) -> Result<String> {
+    let mut instructions = decode(program)?;
+
+    let is_loop = instructions.last()
+        == Some(&Instruction {
+            opcode: Opcode::Jnz,
+            operand: 0,
+        });
+    if is_loop {
+        instructions.pop();
+    }
+    if instructions
+        .iter()
+        .any(|instruction| instruction.opcode == Opcode::Jnz)
+    {
+        return Err(anyhow!(
+            "Cannot render program as pseudo-code, expected a single jnz 0 at the end"
+        ));
+    }
+
+    let indent = if is_loop { "    " } else { "" };
+    let mut lines = Vec::new();
+    if is_loop {
+        lines.push(String::from(match language {
+            Language::Rust => "loop {",
+            Language::C => "do {",
+        }));
+    }
+    for instruction in instructions {
+        lines.push(format!("{indent}{}", statement(&instruction, language)?));
+    }
+    if is_loop {
+        match language {
+            Language::Rust => {
+                lines.extend(["    if a == 0 {", "        break;", "    }", "}"].map(String::from))
+            }
+            Language::C => lines.push(String::from("} while (a != 0);")),
+        }
+    }
+
+    Ok(lines.into_iter().map(|line| format!("{line}\n")).collect())
+}
+
+/// Renders a single instruction, other than jnz, as a statement.
+fn statement(instruction: &Instruction, language: Language) -> Result<String> {
+    let operand = instruction.operand_source()?.to_lowercase();
+    // Literal combo operands are already less than 8.
+    let low_bits = if instruction.operand < 4 {
+        operand.clone()
+    } else {
+        format!("{operand} % 8")
+    };
+
+    match instruction.opcode {
+        Opcode::Adv => Ok(format!("a >>= {operand};")),
+        Opcode::Bxl => Ok(format!("b ^= {operand};")),
+        Opcode::Bst => Ok(format!("b = {low_bits};")),
+        Opcode::Jnz => Err(anyhow!("Cannot render jnz as a statement")),
+        Opcode::Bxc => Ok(String::from("b ^= c;")),
+        Opcode::Out => match language {
+            Language::Rust => Ok(format!("output.push({low_bits});")),
+            Language::C => Ok(format!("output({low_bits});")),
+        },
+        Opcode::Bdv => Ok(format!("b = a >> {operand};")),
+        Opcode::Cdv => Ok(format!("c = a >> {operand};")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The program of the part 2 example.
+    const PROGRAM: [u8; 6] = [0, 3, 5, 4, 3, 0];
+
+    #[test]
+    fn disassemble_program() -> Result<()> {
+        assert_eq!(disassemble(&PROGRAM)?, "adv 3\nout A\njnz 0\n");
+        assert_eq!(
+            disassemble(&[2, 4, 1, 3, 4, 4, 7, 5])?,
+            "bst A\nbxl 3\nbxc\ncdv B\n"
+        );
+        assert!(disassemble(&[0, 7]).is_err());
+        assert!(disassemble(&[8, 0]).is_err());
+        assert!(disassemble(&[0]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_pseudo_code() -> Result<()> {
+        assert_eq!(
+            pseudo_code(&PROGRAM, Language::Rust)?,
+            "loop {\n    a >>= 3;\n    output.push(a % 8);\n    if a == 0 {\n        break;\n    }\n}\n"
+        );
+        assert_eq!(
+            pseudo_code(&PROGRAM, Language::C)?,
+            "do {\n    a >>= 3;\n    output(a % 8);\n} while (a != 0);\n"
+        );
+        assert_eq!(
+            pseudo_code(&[2, 6, 6, 1], Language::C)?,
+            "b = c % 8;\nb = a >> 1;\n"
+        );
+        assert!(pseudo_code(&[3, 2, 0, 1], Language::Rust).is_err());
+
+        Ok(())
+    }
+}
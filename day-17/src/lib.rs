@@ -0,0 +1,438 @@
+use std::{borrow::Cow, str::FromStr};
+
+use anyhow::{Result, anyhow};
+use num_traits::Zero;
+
+use cli::{Answer, ParseError, Solution, parse_fragment};
+
+pub use assembler::{assemble, format_program};
+pub use debugger::{Breakpoint, Debugger};
+pub use instruction::{
+    Instruction, Language, Opcode, decode, disassemble, disassemble_with_labels, pseudo_code,
+};
+pub use register::Register;
+use register::{append_bits, into_answer, low_bits, shift_right};
+pub use trace::{Registers, Step};
+
+mod assembler;
+mod debugger;
+mod instruction;
+mod register;
+mod trace;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Computer;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Computer::from_str(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(into_answer(part_2(input)?))
+    }
+}
+
+pub fn part_1(computer: &Computer) -> Result<String> {
+    let mut computer = computer.clone();
+
+    computer.run()?;
+
+    Ok(computer
+        .output
+        .into_iter()
+        .fold(String::new(), |acc, value| {
+            if acc.is_empty() {
+                value.to_string()
+            } else {
+                format!("{},{}", acc, value)
+            }
+        }))
+}
+
+pub fn part_2(computer: &Computer) -> Result<Register> {
+    reverse_search_a(computer, &computer.program)
+}
+
+#[cfg(test)]
+fn match_program(input: &str) -> Result<u32> {
+    let computer = Computer::from_str(input)?;
+
+    match_output(&computer, &computer.program)
+}
+
+/// Finds the lowest value of register A that makes the computer output the target, by brute force.
+#[cfg(test)]
+fn match_output(computer: &Computer, target: &[u8]) -> Result<u32> {
+    let mut a = 0;
+    loop {
+        let mut computer = computer.clone();
+        computer.a = Register::from(a);
+
+        match computer.run_with_output_matching(target) {
+            Ok(()) => {
+                if computer.output == target {
+                    break;
+                }
+            }
+            Err(error) => {
+                if error.to_string() != "Program-output mismatch" {
+                    return Err(error);
+                }
+            }
+        }
+
+        a += 1;
+    }
+
+    Ok(a)
+}
+
+/// Finds the lowest value of register A that makes the computer output the target.
+///
+/// The program must loop until A is 0, shifting A right by 3 bits and outputting one value per
+/// loop. So the last value output only depends on the top 3 bits of A, the one before it on the
+/// next 3 bits, and so on. Working backwards from the tail of the target, each candidate for A is
+/// extended by 3 bits at a time, and kept if running the program on it outputs the tail so far.
+fn reverse_search_a(computer: &Computer, target: &[u8]) -> Result<Register> {
+    if !computer.program.chunks(2).any(|op| op == [0, 3]) {
+        return Err(anyhow!(
+            "Cannot search for register A, program does not shift A by 3 bits"
+        ));
+    }
+
+    let mut candidates = vec![Register::zero()];
+    for tail_len in 1..=target.len() {
+        let tail = &target[target.len() - tail_len..];
+
+        let mut next_candidates = Vec::new();
+        for candidate in &candidates {
+            for bits in 0..8 {
+                let mut computer = computer.clone();
+                computer.a = append_bits(candidate, bits)?;
+                computer.run()?;
+
+                if computer.output == tail {
+                    next_candidates.push(append_bits(candidate, bits)?);
+                }
+            }
+        }
+
+        candidates = next_candidates;
+    }
+
+    candidates
+        .into_iter()
+        .min()
+        .ok_or(anyhow!("Cannot find solution for register A"))
+}
+
+#[derive(Clone)]
+pub struct Computer {
+    pub a: Register,
+    pub b: Register,
+    pub c: Register,
+    ip: usize,
+    pub program: Vec<u8>,
+    pub output: Vec<u8>,
+}
+
+impl FromStr for Computer {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+        if lines.len() != 5 {
+            return Err(anyhow!("Invalid input, expected 5 lines"));
+        }
+
+        let a = parse_register(s, lines[0], "A")?;
+        let b = parse_register(s, lines[1], "B")?;
+        let c = parse_register(s, lines[2], "C")?;
+
+        let Some(program) = lines[4].strip_prefix("Program: ") else {
+            return Err(ParseError::at(s, lines[4], "Invalid program").into());
+        };
+        let program = program
+            .split_terminator(",")
+            .map(|value| parse_fragment(s, value))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            a,
+            b,
+            c,
+            ip: 0,
+            program,
+            output: Vec::new(),
+        })
+    }
+}
+
+fn parse_register(input: &str, line: &str, name: &str) -> Result<Register> {
+    let Some(value) = line.strip_prefix(&format!("Register {name}: ")) else {
+        return Err(ParseError::at(input, line, format!("Invalid register {name}")).into());
+    };
+
+    parse_fragment(input, value)
+}
+
+impl Computer {
+    /// Runs the program until it halts.
+    pub fn run(&mut self) -> Result<()> {
+        while !self.is_halted() {
+            self.step()?
+        }
+
+        Ok(())
+    }
+
+    /// Gets the instruction pointer.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Moves the instruction pointer, e.g. to skip or repeat instructions while debugging.
+    pub fn jump(&mut self, ip: usize) {
+        self.ip = ip;
+    }
+
+    /// Checks whether the program has halted, i.e. the instruction pointer is past its end.
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.program.len()
+    }
+
+    pub fn registers(&self) -> Registers {
+        Registers {
+            a: self.a.to_owned(),
+            b: self.b.to_owned(),
+            c: self.c.to_owned(),
+        }
+    }
+
+    /// Executes one instruction, and records what it did.
+    pub fn step_traced(&mut self) -> Result<Step> {
+        let ip = self.ip;
+        let before = self.registers();
+        let output_len = self.output.len();
+
+        self.step()?;
+
+        // The instruction is valid, as it has just been executed.
+        let instruction = Instruction {
+            opcode: Opcode::try_from(self.program[ip])?,
+            operand: self.program[ip + 1],
+        };
+
+        Ok(Step {
+            ip,
+            instruction: instruction.to_string(),
+            before,
+            after: self.registers(),
+            output: self.output.get(output_len).copied(),
+        })
+    }
+
+    fn step(&mut self) -> Result<()> {
+        if self.is_halted() {
+            return Err(anyhow!("Cannot step, program has halted"));
+        }
+        if self.ip == self.program.len() - 1 {
+            return Err(anyhow!("Cannot get operand"));
+        }
+        let operand = self.program[self.ip + 1];
+
+        match self.program[self.ip] {
+            0 => self.a = self.divide_a(operand)?,
+            1 => self.b ^= Register::from(operand),
+            2 => {
+                let value = self.combo_operand_value(operand)?;
+                self.b = Register::from(low_bits(&value));
+            }
+            3 => {
+                if !self.a.is_zero() {
+                    self.ip = operand as usize;
+
+                    return Ok(());
+                }
+            }
+            4 => self.b ^= &self.c,
+            5 => {
+                let value = self.combo_operand_value(operand)?;
+                self.output.push(low_bits(&value));
+            }
+            6 => self.b = self.divide_a(operand)?,
+            7 => self.c = self.divide_a(operand)?,
+            _ => return Err(anyhow!("Invalid opcode: {}", self.program[self.ip])),
+        }
+
+        self.ip += 2;
+
+        Ok(())
+    }
+
+    /// Gets the value of a combo operand, borrowing it from the register if any.
+    fn combo_operand_value(&self, combo_operand: u8) -> Result<Cow<'_, Register>> {
+        match combo_operand {
+            0..=3 => Ok(Cow::Owned(Register::from(combo_operand))),
+            4 => Ok(Cow::Borrowed(&self.a)),
+            5 => Ok(Cow::Borrowed(&self.b)),
+            6 => Ok(Cow::Borrowed(&self.c)),
+            _ => Err(anyhow!("Invalid combo operand: {}", combo_operand)),
+        }
+    }
+
+    /// Divides register A by 2 to the power of the combo operand.
+    fn divide_a(&self, combo_operand: u8) -> Result<Register> {
+        let exponent = self.combo_operand_value(combo_operand)?;
+
+        Ok(shift_right(&self.a, &exponent))
+    }
+
+    /// Runs the program, but stops as soon as the output no longer matches the target.
+    #[cfg(test)]
+    fn run_with_output_matching(&mut self, target: &[u8]) -> Result<()> {
+        while self.ip < self.program.len() {
+            self.step()?;
+
+            if !target.starts_with(&self.output) {
+                return Err(anyhow!("Program-output mismatch"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example, trim_newlines};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(17, "part-1")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        let example = load_example!(17, "part-2")?;
+
+        example.check(&mut Solver, Part::Part2)?;
+        assert_eq!(
+            example.answer(Part::Part2),
+            Some(match_program(&example.input)?.to_string().as_str())
+        );
+
+        Ok(())
+    }
+
+    /// A program in the style of the puzzle input, which loops until A is 0.
+    const PROGRAM: &str =
+        "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,3,7,5,0,3,1,5,4,4,5,5,3,0";
+
+    #[test]
+    fn confirm_answer_that_does_not_fit_in_32_bits() -> Result<()> {
+        let mut computer = Computer::from_str(PROGRAM)?;
+        computer.a = Register::from(236539226447469u64);
+        computer.run()?;
+
+        assert_eq!(computer.output, computer.program);
+
+        Ok(())
+    }
+
+    #[test]
+    fn divide_by_power_of_2_beyond_register_width() -> Result<()> {
+        let mut computer = Computer::from_str(
+            "Register A: 123456789\nRegister B: 64\nRegister C: 0\n\nProgram: 0,5,5,4",
+        )?;
+        computer.run()?;
+
+        assert_eq!(computer.a, Register::zero());
+        assert_eq!(computer.output, [0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reverse_search_a_with_shift_beyond_register_width() -> Result<()> {
+        // cdv A shifts by A itself, so by 64 bits or more once A is large enough.
+        let mut computer = Computer::from_str(
+            "Register A: 1000\nRegister B: 0\nRegister C: 0\n\nProgram: 7,4,0,3,5,4,3,0",
+        )?;
+        computer.run()?;
+        let target = computer.output;
+
+        let computer = Computer::from_str(
+            "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 7,4,0,3,5,4,3,0",
+        )?;
+        assert_eq!(
+            reverse_search_a(&computer, &target)?,
+            Register::from(match_output(&computer, &target)?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reverse_search_a_beyond_register_width() -> Result<()> {
+        let computer = Computer::from_str(
+            "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 5,4,0,3,3,0",
+        )?;
+        let result = reverse_search_a(&computer, &[7; 22]);
+
+        #[cfg(not(feature = "bigint"))]
+        assert!(result.is_err_and(|error| error.to_string().contains("bigint")));
+
+        #[cfg(feature = "bigint")]
+        assert_eq!(result?.bits(), 66);
+
+        Ok(())
+    }
+
+    #[test]
+    fn trace_steps() -> Result<()> {
+        let example = include_str!("../../examples/day-17/part-1.txt");
+        let mut computer = Computer::from_str(trim_newlines(example))?;
+
+        let step = computer.step_traced()?;
+        assert_eq!(
+            serde_json::to_string(&step)?,
+            r#"{"ip":0,"instruction":"adv 1","before":{"a":729,"b":0,"c":0},"after":{"a":364,"b":0,"c":0},"output":null}"#
+        );
+
+        let step = computer.step_traced()?;
+        assert_eq!((step.ip, step.output), (2, Some(4)));
+        assert_eq!(step.before, step.after);
+
+        computer.jump(6);
+        assert!(computer.is_halted());
+        assert!(computer.step_traced().is_err());
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn reverse_search_a_matches_brute_force(a in 1..4096u32) {
+            let mut computer = Computer::from_str(PROGRAM).unwrap();
+            computer.a = Register::from(a);
+            computer.run().unwrap();
+            let target = computer.output;
+
+            let computer = Computer::from_str(PROGRAM).unwrap();
+            prop_assert_eq!(
+                reverse_search_a(&computer, &target).unwrap(),
+                Register::from(match_output(&computer, &target).unwrap())
+            );
+        }
+    }
+}
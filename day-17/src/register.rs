@@ -0,0 +1,77 @@
+use anyhow::Result;
+#[cfg(not(feature = "bigint"))]
+use anyhow::anyhow;
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use serde::Serializer;
+
+use cli::Answer;
+
+/// The value of a register, 64 bits wide.
+#[cfg(not(feature = "bigint"))]
+pub type Register = u64;
+
+/// The value of a register, arbitrarily wide.
+#[cfg(feature = "bigint")]
+pub type Register = BigUint;
+
+/// Shifts the value right, i.e. divides it by 2 to the power of the shift.
+///
+/// Shifting by the width of the register or more gives 0, instead of overflowing.
+#[cfg(not(feature = "bigint"))]
+pub fn shift_right(value: &Register, shift: &Register) -> Register {
+    u32::try_from(*shift)
+        .ok()
+        .and_then(|shift| value.checked_shr(shift))
+        .unwrap_or(0)
+}
+
+/// Shifts the value right, i.e. divides it by 2 to the power of the shift.
+#[cfg(feature = "bigint")]
+pub fn shift_right(value: &Register, shift: &Register) -> Register {
+    shift
+        .to_u64()
+        .map_or_else(Register::default, |shift| value >> shift)
+}
+
+/// Shifts the value left by 3 bits, and sets the low 3 bits.
+///
+/// Fails instead of dropping the top bits if the result does not fit in the register.
+#[cfg(not(feature = "bigint"))]
+pub fn append_bits(value: &Register, bits: u8) -> Result<Register> {
+    if value.leading_zeros() < 3 {
+        return Err(anyhow!(
+            "Cannot fit register A in 64 bits, enable the bigint feature"
+        ));
+    }
+
+    Ok((value << 3) | Register::from(bits))
+}
+
+/// Shifts the value left by 3 bits, and sets the low 3 bits.
+#[cfg(feature = "bigint")]
+pub fn append_bits(value: &Register, bits: u8) -> Result<Register> {
+    Ok((value << 3) | Register::from(bits))
+}
+
+/// Gets the value modulo 8.
+pub fn low_bits(value: &Register) -> u8 {
+    (value % Register::from(8u8)).to_u8().unwrap_or_default()
+}
+
+/// Gets the value as an [Answer], falling back to text if it is too big for an integer.
+pub fn into_answer(value: Register) -> Answer {
+    match value.to_u64() {
+        Some(value) => Answer::from(value),
+        None => Answer::from(value.to_string()),
+    }
+}
+
+/// Serializes the value as a number, or as a string if it is too big for a JSON integer.
+pub fn serialize<S: Serializer>(value: &Register, serializer: S) -> Result<S::Ok, S::Error> {
+    match value.to_u64() {
+        Some(value) => serializer.serialize_u64(value),
+        None => serializer.collect_str(value),
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+
+use crate::{Register, register};
+
+/// The values of the 3 registers at one point of a run.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Registers {
+    #[serde(serialize_with = "register::serialize")]
+    pub a: Register,
+
+    #[serde(serialize_with = "register::serialize")]
+    pub b: Register,
+
+    #[serde(serialize_with = "register::serialize")]
+    pub c: Register,
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+    }
+}
+
+/// A record of one instruction executed by the computer, e.g. to trace a run as JSON lines.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Step {
+    /// The instruction pointer that the instruction was read from.
+    pub ip: usize,
+
+    /// The instruction, as shown by the disassembler.
+    pub instruction: String,
+
+    pub before: Registers,
+    pub after: Registers,
+
+    /// The value output by the instruction, if any.
+    pub output: Option<u8>,
+}
@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use cli::trim_newlines;
+use day_17::{Computer, Register};
+
+#[test]
+fn run_program() -> Result<()> {
+    let example = include_str!("../../examples/day-17/part-1.txt");
+
+    let mut computer = Computer::from_str(trim_newlines(example))?;
+    computer.run()?;
+
+    assert_eq!(computer.output, [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+
+    Ok(())
+}
+
+#[test]
+fn run_program_that_outputs_itself() -> Result<()> {
+    let example = include_str!("../../examples/day-17/part-2.txt");
+
+    let mut computer = Computer::from_str(trim_newlines(example))?;
+    computer.a = Register::from(117440u32);
+    computer.run()?;
+
+    assert_eq!(computer.output, computer.program);
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use anyhow::{Result, anyhow};
+use pathfinding::prelude::dijkstra;
+
+use cli::{Answer, Solution, parse_fragment, parse_grid_length, parse_lines};
+use grid::{Coord, Grid};
+
+pub struct Solver {
+    pub grid_size: GridSize,
+    pub bytes_count: usize,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            grid_size: (71, 71),
+            bytes_count: 1024,
+        }
+    }
+}
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Position>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_bytes(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input, self.grid_size, self.bytes_count)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input, self.grid_size, self.bytes_count)?.into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("width", self.grid_size.0.to_string()),
+            ("height", self.grid_size.1.to_string()),
+            ("bytes_count", self.bytes_count.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "width" => self.grid_size.0 = parse_grid_length(value)?,
+            "height" => self.grid_size.1 = parse_grid_length(value)?,
+            // The square grid size from before width and height were separate.
+            "grid_size" => {
+                let length = parse_grid_length(value)?;
+                self.grid_size = (length, length);
+            }
+            "bytes_count" => self.bytes_count = value.parse()?,
+            _ => return Err(anyhow!("Invalid parameter: {}", key)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part_1(bytes: &[Position], grid_size: GridSize, bytes_count: usize) -> Result<u32> {
+    shortest_path_through_grid(bytes, grid_size, bytes_count)
+}
+
+pub fn part_2(bytes: &[Position], grid_size: GridSize, bytes_count: usize) -> Result<String> {
+    first_blocking_byte(bytes, grid_size, bytes_count)
+}
+
+/// (x, y)
+pub type Position = (usize, usize);
+
+/// (width, height)
+pub type GridSize = (usize, usize);
+
+fn shortest_path_through_grid(
+    bytes: &[Position],
+    grid_size: GridSize,
+    bytes_count: usize,
+) -> Result<u32> {
+    let mut grid = Grid::new(grid_size.0, grid_size.1, false);
+    bytes
+        .iter()
+        .take(bytes_count)
+        .for_each(|&byte| corrupt(&mut grid, byte));
+
+    dijkstra(
+        &(0, 0),
+        |node| successors(node, &grid),
+        |node| *node == exit(grid_size),
+    )
+    .map(|shortest_path| shortest_path.1)
+    .ok_or(anyhow!("Cannot find shortest path"))
+}
+
+/// Gets the coord of the exit, at the bottom right corner.
+fn exit((width, height): GridSize) -> Coord {
+    (height - 1, width - 1)
+}
+
+/// Marks the coord of the byte as corrupted. Bytes that fall outside the grid are ignored.
+fn corrupt(grid: &mut Grid<bool>, (x, y): Position) {
+    if let Some(cell) = grid.get_mut((y, x)) {
+        *cell = true;
+    }
+}
+
+fn successors(coord: &Coord, grid: &Grid<bool>) -> Vec<(Coord, u32)> {
+    grid.neighbours_4(*coord)
+        .filter(|&neighbour| !grid[neighbour])
+        .map(|neighbour| (neighbour, 1))
+        .collect()
+}
+
+/// Finds the first byte that blocks all path between S and E.
+///
+/// Use skip_bytes_count (bytes_count from part 1) to skip over the blocked path checks where we
+/// know for certain that an unhindered path exists.
+fn first_blocking_byte(
+    bytes: &[Position],
+    grid_size: GridSize,
+    skip_bytes_count: usize,
+) -> Result<String> {
+    bytes
+        .iter()
+        .scan(
+            Grid::new(grid_size.0, grid_size.1, false),
+            |state, &byte| {
+                corrupt(state, byte);
+
+                Some((state.clone(), byte))
+            },
+        )
+        .skip(skip_bytes_count)
+        .find(|(grid, _)| {
+            dijkstra(
+                &(0, 0),
+                |node| successors(node, grid),
+                |node| *node == exit(grid_size),
+            )
+            .is_none()
+        })
+        .map(|(_, byte)| format!("{},{}", byte.0, byte.1))
+        .ok_or(anyhow!("Cannot find first byte that blocks path"))
+}
+
+pub fn parse_input_into_bytes(input: &str) -> Result<Vec<Position>> {
+    parse_lines(input, |line| {
+        let Some((x, y)) = line.split_once(",") else {
+            return Err(anyhow!("Cannot split input into x and y"));
+        };
+
+        Ok((parse_fragment(line, x)?, parse_fragment(line, y)?))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(18, "example")?.check(&mut Solver::default(), Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(18, "example")?.check(&mut Solver::default(), Part::Part2)
+    }
+
+    #[test]
+    fn example_1_wide() -> Result<()> {
+        load_example!(18, "wide")?.check(&mut Solver::default(), Part::Part1)
+    }
+
+    #[test]
+    fn example_2_wide() -> Result<()> {
+        load_example!(18, "wide")?.check(&mut Solver::default(), Part::Part2)
+    }
+
+    #[test]
+    fn set_square_grid_size() -> Result<()> {
+        let mut solver = Solver::default();
+
+        Solution::set_param(&mut solver, "grid_size", "7")?;
+        assert_eq!(solver.grid_size, (7, 7));
+        assert!(Solution::set_param(&mut solver, "grid_size", "0").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_patterns_and_designs(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1((patterns, designs): &(Vec<&str>, Vec<&str>)) -> Result<usize> {
+    let mut cache = HashMap::new();
+    Ok(designs
+        .iter()
+        .filter(|&&design| is_possible_design(design, patterns, &mut cache))
+        .count())
+}
+
+pub fn part_2((patterns, designs): &(Vec<&str>, Vec<&str>)) -> Result<u64> {
+    let mut cache = HashMap::new();
+    Ok(designs
+        .iter()
+        .map(|&design| pattern_combinations(design, patterns, &mut cache))
+        .sum())
+}
+
+pub fn parse_input_into_patterns_and_designs(input: &str) -> Result<(Vec<&str>, Vec<&str>)> {
+    let Some((patterns, designs)) = input.split_once("\n\n") else {
+        return Err(anyhow!("Cannot split input into patterns and designs"));
+    };
+
+    let patterns = patterns.split_terminator(", ").collect();
+    let designs = designs.lines().collect();
+
+    Ok((patterns, designs))
+}
+
+fn is_possible_design<'a>(
+    design: &'a str,
+    patterns: &[&str],
+    cache: &mut HashMap<&'a str, bool>,
+) -> bool {
+    if cache.contains_key(design) {
+        return cache[design];
+    }
+
+    if design.is_empty() {
+        return *cache.entry(design).or_insert(true);
+    }
+
+    let possible_sub_designs = patterns.iter().any(|pattern| {
+        design
+            .strip_prefix(pattern)
+            .is_some_and(|design| is_possible_design(design, patterns, cache))
+    });
+    *cache.entry(design).or_insert(possible_sub_designs)
+}
+
+fn pattern_combinations<'a>(
+    design: &'a str,
+    patterns: &[&str],
+    cache: &mut HashMap<&'a str, u64>,
+) -> u64 {
+    if cache.contains_key(design) {
+        return cache[design];
+    }
+
+    if design.is_empty() {
+        return *cache.entry(design).or_insert(1);
+    }
+
+    let sub_design_combinations = patterns
+        .iter()
+        .map(|pattern| {
+            design
+                .strip_prefix(pattern)
+                .map_or(0, |design| pattern_combinations(design, patterns, cache))
+        })
+        .sum();
+    *cache.entry(design).or_insert(sub_design_combinations)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(19, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(19, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+
+use cli::{Answer, Solution, parse_fragment, parse_lines};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_reports(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(reports: &[Vec<u32>]) -> Result<usize> {
+    Ok(reports.iter().filter(|report| is_safe(report)).count())
+}
+
+pub fn part_2(reports: &[Vec<u32>]) -> Result<usize> {
+    Ok(reports
+        .iter()
+        .filter(|report| is_safe_with_tolerance(report))
+        .count())
+}
+
+pub fn parse_input_into_reports(input: &str) -> Result<Vec<Vec<u32>>> {
+    parse_lines(input, |line| {
+        line.split_whitespace()
+            .map(|level| parse_fragment(line, level))
+            .collect()
+    })
+}
+
+fn is_safe(report: &[u32]) -> bool {
+    all_increasing_or_decreasing(report) && all_acceptable_gap(report)
+}
+
+fn all_increasing_or_decreasing(report: &[u32]) -> bool {
+    report
+        .windows(2)
+        .map(|window| match window[0].cmp(&window[1]) {
+            Ordering::Less => 1,
+            Ordering::Equal => 0,
+            Ordering::Greater => -1,
+        })
+        .sum::<i32>()
+        .unsigned_abs() as usize
+        == report.len() - 1
+}
+
+fn all_acceptable_gap(report: &[u32]) -> bool {
+    report
+        .windows(2)
+        .all(|window| (1..=3).contains(&window[0].abs_diff(window[1])))
+}
+
+fn is_safe_with_tolerance(report: &[u32]) -> bool {
+    if is_safe(report) {
+        return true;
+    }
+
+    for index in 0..report.len() {
+        let mut report = Vec::from(report);
+        report.remove(index);
+
+        if is_safe(&report) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(2, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(2, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
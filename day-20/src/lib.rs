@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use itertools::Itertools;
+use pathfinding::prelude::dijkstra;
+
+use cli::{Answer, Solution};
+use grid::{Coord, Grid};
+
+pub struct Solver {
+    pub part_1_max_shortcut: usize,
+    pub part_2_max_shortcut: usize,
+    pub part_1_min_savings: usize,
+    pub part_2_min_savings: usize,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            part_1_max_shortcut: 2,
+            part_2_max_shortcut: 20,
+            part_1_min_savings: 100,
+            part_2_min_savings: 100,
+        }
+    }
+}
+
+impl Solution for Solver {
+    type Input<'a> = Racetrack;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Racetrack::from_str(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input, self.part_1_max_shortcut, self.part_1_min_savings)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input, self.part_2_max_shortcut, self.part_2_min_savings)?.into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("part_1_max_shortcut", self.part_1_max_shortcut.to_string()),
+            ("part_2_max_shortcut", self.part_2_max_shortcut.to_string()),
+            ("part_1_min_savings", self.part_1_min_savings.to_string()),
+            ("part_2_min_savings", self.part_2_min_savings.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "part_1_max_shortcut" => self.part_1_max_shortcut = value.parse()?,
+            "part_2_max_shortcut" => self.part_2_max_shortcut = value.parse()?,
+            "part_1_min_savings" => self.part_1_min_savings = value.parse()?,
+            "part_2_min_savings" => self.part_2_min_savings = value.parse()?,
+            _ => return Err(anyhow!("Invalid parameter: {}", key)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part_1(racetrack: &Racetrack, max_shortcut: usize, min_savings: usize) -> Result<usize> {
+    count_shortcuts(racetrack, max_shortcut, min_savings)
+}
+
+pub fn part_2(racetrack: &Racetrack, max_shortcut: usize, min_savings: usize) -> Result<usize> {
+    count_shortcuts(racetrack, max_shortcut, min_savings)
+}
+
+pub struct Racetrack {
+    pub layout: Grid<u8>,
+    pub start: Coord,
+    pub end: Coord,
+}
+
+impl FromStr for Racetrack {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let layout = Grid::parse(s, Ok)?;
+        let start = layout.find(&b'S').ok_or(anyhow!("Cannot find start"))?;
+        let end = layout.find(&b'E').ok_or(anyhow!("Cannot find end"))?;
+
+        Ok(Self { layout, start, end })
+    }
+}
+
+fn count_shortcuts(
+    racetrack: &Racetrack,
+    max_shortcut: usize,
+    min_savings: usize,
+) -> Result<usize> {
+    let Some((shortest_path, _)) = dijkstra(
+        &racetrack.start,
+        |node| successors(node, racetrack),
+        |node| *node == racetrack.end,
+    ) else {
+        return Err(anyhow!("Cannot find shortest path"));
+    };
+
+    Ok((0..shortest_path.len())
+        .zip(shortest_path)
+        .tuple_combinations()
+        .filter(|((index_1, coord_1), (index_2, coord_2))| {
+            // If the shortcut runs along the shortest path, savings will be 0.
+
+            let shortcut = manhatten_distance(coord_1, coord_2);
+            let savings = index_1.abs_diff(*index_2) - shortcut;
+
+            shortcut <= max_shortcut && savings >= min_savings
+        })
+        .count())
+}
+
+fn successors(coord: &Coord, racetrack: &Racetrack) -> Vec<(Coord, u32)> {
+    racetrack
+        .layout
+        .neighbours_4(*coord)
+        .filter(|&neighbour| racetrack.layout[neighbour] != b'#')
+        .map(|neighbour| (neighbour, 1))
+        .collect()
+}
+
+fn manhatten_distance(coord: &Coord, other: &Coord) -> usize {
+    coord.0.abs_diff(other.0) + coord.1.abs_diff(other.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(20, "example")?.check(&mut Solver::default(), Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(20, "example")?.check(&mut Solver::default(), Part::Part2)
+    }
+
+    #[test]
+    fn example_1_borderless() -> Result<()> {
+        load_example!(20, "borderless")?.check(&mut Solver::default(), Part::Part1)
+    }
+
+    #[test]
+    fn example_2_borderless() -> Result<()> {
+        load_example!(20, "borderless")?.check(&mut Solver::default(), Part::Part2)
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use button_sequences::BUTTON_SEQUENCES;
+use cli::{Answer, Solution, parse_fragment, parse_lines};
+
+mod button_sequences;
+
+pub struct Solver {
+    pub part_1_directional_keypads: u8,
+    pub part_2_directional_keypads: u8,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self {
+            part_1_directional_keypads: 3,
+            part_2_directional_keypads: 26,
+        }
+    }
+}
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Code<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_codes(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input, self.part_1_directional_keypads)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input, self.part_2_directional_keypads)?.into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "part_1_directional_keypads",
+                self.part_1_directional_keypads.to_string(),
+            ),
+            (
+                "part_2_directional_keypads",
+                self.part_2_directional_keypads.to_string(),
+            ),
+        ]
+    }
+
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+        let directional_keypads = match key {
+            "part_1_directional_keypads" => &mut self.part_1_directional_keypads,
+            "part_2_directional_keypads" => &mut self.part_2_directional_keypads,
+            _ => return Err(anyhow!("Invalid parameter: {}", key)),
+        };
+
+        // There is always at least the directional keypad that we press.
+        match value.parse()? {
+            0 => Err(anyhow!("Invalid directional keypads count: {}", value)),
+            count => {
+                *directional_keypads = count;
+
+                Ok(())
+            }
+        }
+    }
+}
+
+pub fn part_1(codes: &[Code], directional_keypads: u8) -> Result<u64> {
+    let mut cache = HashMap::new();
+    Ok(codes
+        .iter()
+        .map(|(code, numeric_part)| {
+            code_button_presses(code, directional_keypads, &mut cache) * numeric_part
+        })
+        .sum())
+}
+
+pub fn part_2(codes: &[Code], directional_keypads: u8) -> Result<u64> {
+    let mut cache = HashMap::new();
+    Ok(codes
+        .iter()
+        .map(|(code, numeric_part)| {
+            code_button_presses(code, directional_keypads, &mut cache) * numeric_part
+        })
+        .sum())
+}
+
+/// (code, numeric_part)
+pub type Code<'a> = (&'a str, u64);
+
+pub fn parse_input_into_codes(input: &str) -> Result<Vec<Code<'_>>> {
+    parse_lines(input, |code| {
+        let Some(numeric_part) = code.strip_suffix("A") else {
+            return Err(anyhow!("Invalid code, expected A at the end"));
+        };
+
+        Ok((code, parse_fragment(code, numeric_part)?))
+    })
+}
+
+fn code_button_presses(
+    code: &str,
+    directional_keypads: u8,
+    cache: &mut HashMap<(u8, u8, u8), u64>,
+) -> u64 {
+    let mut code_sequence = vec![b'A'];
+    code_sequence.extend(code.bytes());
+    code_sequence
+        .windows(2)
+        .map(|window| count_button_presses(window[0], window[1], directional_keypads - 1, cache))
+        .sum()
+}
+
+fn count_button_presses(
+    start_pos: u8,
+    end_pos: u8,
+    directional_keypads: u8,
+    cache: &mut HashMap<(u8, u8, u8), u64>,
+) -> u64 {
+    if cache.contains_key(&(start_pos, end_pos, directional_keypads)) {
+        return cache[&(start_pos, end_pos, directional_keypads)];
+    }
+
+    let button_sequence = &BUTTON_SEQUENCES[&(start_pos, end_pos)];
+
+    if directional_keypads == 0 {
+        return *cache
+            .entry((start_pos, end_pos, directional_keypads))
+            .or_insert(button_sequence.len() as u64 - 1);
+    }
+
+    let redirected_presses = button_sequence
+        .windows(2)
+        .map(|window| count_button_presses(window[0], window[1], directional_keypads - 1, cache))
+        .sum();
+    *cache
+        .entry((start_pos, end_pos, directional_keypads))
+        .or_insert(redirected_presses)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(21, "example")?.check(&mut Solver::default(), Part::Part1)
+    }
+}
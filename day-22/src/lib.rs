@@ -0,0 +1,149 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution, parse_fragment, parse_lines};
+
+pub struct Solver {
+    pub iterations: usize,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self { iterations: 2000 }
+    }
+}
+
+impl Solution for Solver {
+    type Input<'a> = Vec<u64>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_secrets(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input, self.iterations)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input, self.iterations)?.into())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("iterations", self.iterations.to_string())]
+    }
+
+    fn set_param(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "iterations" => self.iterations = value.parse()?,
+            _ => return Err(anyhow!("Invalid parameter: {}", key)),
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part_1(secrets: &[u64], iterations: usize) -> Result<u64> {
+    Ok(secrets
+        .iter()
+        .map(|secret| nth_secret(*secret, iterations))
+        .sum())
+}
+
+pub fn part_2(secrets: &[u64], iterations: usize) -> Result<u64> {
+    max_bananas_purchasable(secrets, iterations)
+}
+
+pub fn parse_input_into_secrets(input: &str) -> Result<Vec<u64>> {
+    parse_lines(input, |line| parse_fragment(line, line))
+}
+
+fn nth_secret(mut secret: u64, n: usize) -> u64 {
+    for _ in 0..n {
+        secret = next_secret(secret);
+    }
+
+    secret
+}
+
+fn next_secret(mut secret: u64) -> u64 {
+    secret = mix_and_prune(secret, secret * 64);
+    secret = mix_and_prune(secret, secret / 32);
+    secret = mix_and_prune(secret, secret * 2048);
+
+    secret
+}
+
+fn mix_and_prune(secret: u64, number: u64) -> u64 {
+    (secret ^ number) % 16777216
+}
+
+fn max_bananas_purchasable(secrets: &[u64], iterations: usize) -> Result<u64> {
+    let mut bananas_for_sequence = HashMap::new();
+    for secret in secrets {
+        add_bananas_for_sequence(*secret, iterations, &mut bananas_for_sequence);
+    }
+
+    bananas_for_sequence
+        .into_values()
+        .max()
+        .ok_or(anyhow!("Cannot find max bananas purchasable"))
+}
+
+fn add_bananas_for_sequence(
+    secret: u64,
+    iterations: usize,
+    bananas_for_sequence: &mut HashMap<[i64; 4], u64>,
+) {
+    // For each monkey, only the first time the sequence appears can count.
+    let mut sequences_added = HashSet::new();
+
+    (0..iterations)
+        .scan(secret, |state, _| {
+            // Calculate bananas and diff from prev secret.
+
+            let prev_bananas = *state % 10;
+
+            *state = next_secret(*state);
+
+            let bananas = *state % 10;
+            let diff = bananas as i64 - prev_bananas as i64;
+
+            Some((bananas, diff))
+        })
+        .collect::<Vec<_>>()
+        .windows(4)
+        .for_each(|window| {
+            let sequence = [window[0].1, window[1].1, window[2].1, window[3].1];
+
+            if sequences_added.insert(sequence) {
+                *bananas_for_sequence.entry(sequence).or_default() += window[3].0;
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, Puzzle, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(22, "part-1")?.check(&mut Solver::default(), Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(22, "part-2")?.check(&mut Solver::default(), Part::Part2)
+    }
+
+    #[test]
+    fn example_1_after_10_iterations() -> Result<()> {
+        let solver = Solver { iterations: 10 };
+
+        assert_eq!(solver.solve(Part::Part1, "123")?, Answer::Integer(5908254));
+
+        Ok(())
+    }
+}
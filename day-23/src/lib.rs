@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use cli::{Answer, ParseError, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = HashMap<&'a str, HashSet<&'a str>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_connections(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(connections: &HashMap<&str, HashSet<&str>>) -> Result<usize> {
+    Ok(connections
+        .iter()
+        .filter(|(computer, _)| computer.starts_with("t"))
+        .flat_map(|(t_computer, neighbours)| {
+            neighbours
+                .iter()
+                .tuple_combinations()
+                .filter_map(|(&computer_1, &computer_2)| {
+                    if connections[computer_1].contains(&computer_2) {
+                        // Get rid of overlaps between triplets with 2 or more "t" computers.
+                        let mut triplet = [*t_computer, computer_1, computer_2];
+                        triplet.sort();
+
+                        Some(triplet)
+                    } else {
+                        None
+                    }
+                })
+        })
+        .unique()
+        .count())
+}
+
+pub fn part_2(connections: &HashMap<&str, HashSet<&str>>) -> Result<String> {
+    let mut max_clique = HashSet::new();
+    bron_kerborsh(
+        HashSet::new(),
+        connections.keys().copied().collect::<HashSet<_>>(),
+        HashSet::new(),
+        &mut max_clique,
+        connections,
+    );
+
+    Ok(max_clique.into_iter().sorted().join(","))
+}
+
+pub fn parse_input_into_connections(input: &str) -> Result<HashMap<&str, HashSet<&str>>> {
+    let mut connections: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for line in input.lines() {
+        let Some((computer_1, computer_2)) = line.split_once("-") else {
+            return Err(
+                ParseError::at(input, line, "Cannot split input into computers 1 and 2").into(),
+            );
+        };
+
+        connections
+            .entry(computer_1)
+            .or_default()
+            .insert(computer_2);
+        connections
+            .entry(computer_2)
+            .or_default()
+            .insert(computer_1);
+    }
+
+    Ok(connections)
+}
+
+fn bron_kerborsh<'a>(
+    r: HashSet<&'a str>,
+    mut p: HashSet<&'a str>,
+    mut x: HashSet<&'a str>,
+    max_clique: &mut HashSet<&'a str>,
+    connections: &HashMap<&'a str, HashSet<&'a str>>,
+) {
+    if p.is_empty() && x.is_empty() && r.len() > max_clique.len() {
+        *max_clique = r;
+        return;
+    }
+
+    if r.len() + p.len() <= max_clique.len() {
+        return;
+    }
+
+    for v in p.clone() {
+        let mut next_r = r.clone();
+        next_r.insert(v);
+        let next_p = p.intersection(&connections[v]).copied().collect();
+        let next_x = x.intersection(&connections[v]).copied().collect();
+        bron_kerborsh(next_r, next_p, next_x, max_clique, connections);
+
+        p.remove(v);
+        x.insert(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(23, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(23, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, ParseError, Solution, parse_lines, parse_section};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (HashMap<&'a str, bool>, Vec<LogicGate<'a>>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_values_and_logic_gates(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1((values, logic_gates): &(HashMap<&str, bool>, Vec<LogicGate>)) -> Result<u64> {
+    let mut values = extend_values(values.clone(), logic_gates);
+
+    // Just loop until all "z"s are resolved.
+    while !values
+        .iter()
+        .all(|(wire, value)| !wire.starts_with("z") || value.is_some())
+    {
+        for logic_gate in logic_gates {
+            logic_gate.evaluate(&mut values);
+        }
+    }
+
+    values_to_number("z", &values)
+}
+
+pub fn part_2((values, logic_gates): &(HashMap<&str, bool>, Vec<LogicGate>)) -> Result<String> {
+    // We are looking at a 45-bit ripple-carry adder.
+    //
+    // At the very least, we should expect to find the following operations:
+    //   - For lsb:
+    //     - x00 ^ y00 -> z00
+    //     - x00 & y00 -> c01
+    //   - For higher bits:
+    //     - x<n> ^ y<n> -> i<n>
+    //     - x<n> & y<n> -> j<n>
+    //     - i<n> & c<n> -> k<n>
+    //     - i<n> ^ c<n> -> z<n>
+    //     - j<n> | k<n> -> c<n + 1>
+    //   - For msb, the last statement becomes:
+    //     - j44 | k44 -> z45
+    //
+    // For 45 bits we should expect 2 + 44 * 5 = 222 operations, which fits the input.
+    //
+    // Use some pattern matching (Excel works!) to filter out all the correct, expected signals.
+    // From here, there are 3 "-> z"s that are not XORs, hence must be incorrect:
+    //   - z15
+    //   - z05
+    //   - z20
+    // There are also correspondingly 3 XORs that do not involve x and y:
+    //   - fvm XOR mvv
+    //   - bhw XOR sth
+    //   - gcs XOR hdc
+    //
+    // Hence the swaps:
+    //   - z15 <-> htp
+    //   - z05 <-> dkr
+    //   - z20 <-> hhh
+    //
+    // z45 is equivalent to c45, which clears the following:
+    //   - x44 AND y44 -> kbb
+    //   - gqg AND pfh -> khw
+    //   - kbb OR khw -> z45
+    //
+    // Checking z36, we need to swap: rhv <-> ggk.
+
+    // Perform quick verification.
+
+    let replacements = HashMap::from([
+        ("z15", "htp"),
+        ("htp", "z15"),
+        ("z05", "dkr"),
+        ("dkr", "z05"),
+        ("z20", "hhh"),
+        ("hhh", "z20"),
+        ("rhv", "ggk"),
+        ("ggk", "rhv"),
+    ]);
+
+    let mut logic_gates = logic_gates.clone();
+    let mut values = extend_values(values.clone(), &logic_gates);
+
+    logic_gates
+        .iter_mut()
+        .for_each(|logic_gate| match logic_gate {
+            LogicGate::And { output, .. }
+            | LogicGate::Or { output, .. }
+            | LogicGate::Xor { output, .. } => {
+                if replacements.contains_key(output) {
+                    *output = replacements[output];
+                }
+            }
+        });
+
+    while !values
+        .iter()
+        .all(|(wire, value)| !wire.starts_with("z") || value.is_some())
+    {
+        for logic_gate in &logic_gates {
+            logic_gate.evaluate(&mut values);
+        }
+    }
+
+    let x = values_to_number("x", &values)?;
+    let y = values_to_number("y", &values)?;
+    let z = values_to_number("z", &values)?;
+    if x + y != z {
+        return Err(anyhow!("Cannot implement adder: {} + {} != {}", x, y, z));
+    }
+
+    let mut replacements = replacements.into_keys().collect::<Vec<_>>();
+    replacements.sort();
+
+    Ok(replacements.join(","))
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogicGate<'a> {
+    And {
+        input_1: &'a str,
+        input_2: &'a str,
+        output: &'a str,
+    },
+    Or {
+        input_1: &'a str,
+        input_2: &'a str,
+        output: &'a str,
+    },
+    Xor {
+        input_1: &'a str,
+        input_2: &'a str,
+        output: &'a str,
+    },
+}
+
+/// Parses a gate such as `x00 AND y00 -> z00`, borrowing the wire names from the input.
+impl<'a> TryFrom<&'a str> for LogicGate<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &'a str) -> Result<Self> {
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+        if tokens.len() != 5 {
+            return Err(anyhow!("Invalid logic gate, expected 5 tokens"));
+        }
+
+        match tokens[1] {
+            "AND" => Ok(Self::And {
+                input_1: tokens[0],
+                input_2: tokens[2],
+                output: tokens[4],
+            }),
+            "OR" => Ok(Self::Or {
+                input_1: tokens[0],
+                input_2: tokens[2],
+                output: tokens[4],
+            }),
+            "XOR" => Ok(Self::Xor {
+                input_1: tokens[0],
+                input_2: tokens[2],
+                output: tokens[4],
+            }),
+            _ => Err(ParseError::at(s, tokens[1], "Invalid op").into()),
+        }
+    }
+}
+
+impl<'a> LogicGate<'a> {
+    fn evaluate(&self, values: &mut HashMap<&'a str, Option<bool>>) {
+        match self {
+            LogicGate::And {
+                input_1,
+                input_2,
+                output,
+            } => {
+                if let (Some(input_1), Some(input_2), None) =
+                    (values[input_1], values[input_2], values[output])
+                {
+                    values
+                        .entry(output)
+                        .and_modify(|value| *value = Some(input_1 && input_2));
+                }
+            }
+            LogicGate::Or {
+                input_1,
+                input_2,
+                output,
+            } => {
+                if let (Some(input_1), Some(input_2), None) =
+                    (values[input_1], values[input_2], values[output])
+                {
+                    values
+                        .entry(output)
+                        .and_modify(|value| *value = Some(input_1 || input_2));
+                }
+            }
+            LogicGate::Xor {
+                input_1,
+                input_2,
+                output,
+            } => {
+                if let (Some(input_1), Some(input_2), None) =
+                    (values[input_1], values[input_2], values[output])
+                {
+                    values
+                        .entry(output)
+                        .and_modify(|value| *value = Some(input_1 ^ input_2));
+                }
+            }
+        }
+    }
+}
+
+pub fn parse_input_into_values_and_logic_gates<'a>(
+    input: &'a str,
+) -> Result<(HashMap<&'a str, bool>, Vec<LogicGate<'a>>)> {
+    let Some((values, logic_gates)) = input.split_once("\n\n") else {
+        return Err(anyhow!("Cannot split into values and logic gates"));
+    };
+
+    let values = parse_section(input, values, |values| {
+        parse_lines(values, |line| {
+            let Some((wire, value)) = line.split_once(": ") else {
+                return Err(anyhow!("Cannot split input into wire and value"));
+            };
+
+            let value = match value {
+                "0" => false,
+                "1" => true,
+                _ => return Err(ParseError::at(line, value, "Invalid value").into()),
+            };
+
+            Ok((wire, value))
+        })
+    })?;
+
+    let logic_gates = parse_section(input, logic_gates, |logic_gates| {
+        parse_lines(logic_gates, LogicGate::try_from)
+    })?;
+
+    Ok((values.into_iter().collect(), logic_gates))
+}
+
+/// Extends values by creating entries for all wires, and wrapping with [Option].
+fn extend_values<'a>(
+    values: HashMap<&'a str, bool>,
+    logic_gates: &[LogicGate<'a>],
+) -> HashMap<&'a str, Option<bool>> {
+    let mut values = values
+        .into_iter()
+        .map(|(wire, value)| (wire, Some(value)))
+        .collect::<HashMap<_, _>>();
+
+    for logic_gate in logic_gates {
+        match *logic_gate {
+            LogicGate::And {
+                input_1,
+                input_2,
+                output,
+            }
+            | LogicGate::Or {
+                input_1,
+                input_2,
+                output,
+            }
+            | LogicGate::Xor {
+                input_1,
+                input_2,
+                output,
+            } => {
+                values.entry(input_1).or_default();
+                values.entry(input_2).or_default();
+                values.entry(output).or_default();
+            }
+        }
+    }
+
+    values
+}
+
+/// Combines the bits represented by wires with wire_prefix into a [u64].
+fn values_to_number(wire_prefix: &str, values: &HashMap<&str, Option<bool>>) -> Result<u64> {
+    let mut bits = values
+        .iter()
+        .filter(|(wire, _)| wire.starts_with(wire_prefix))
+        .map(|(wire, value)| match value {
+            Some(true) => Ok((wire, 1)),
+            Some(false) => Ok((wire, 0)),
+            _ => Err(anyhow!("Attempting to use unresolved value: {}", wire)),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    bits.sort_by_key(|z| z.0);
+    bits.reverse();
+
+    Ok(bits
+        .into_iter()
+        .fold(0, |acc, (_, bit)| (acc << 1) + bit as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1a() -> Result<()> {
+        load_example!(24, "a")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_1b() -> Result<()> {
+        load_example!(24, "b")?.check(&mut Solver, Part::Part1)
+    }
+}
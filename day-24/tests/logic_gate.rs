@@ -0,0 +1,31 @@
+use anyhow::Result;
+
+use cli::trim_newlines;
+use day_24::{LogicGate, parse_input_into_values_and_logic_gates, part_1};
+
+#[test]
+fn parse_logic_gate() -> Result<()> {
+    assert_eq!(
+        LogicGate::try_from("x00 AND y00 -> z00")?,
+        LogicGate::And {
+            input_1: "x00",
+            input_2: "y00",
+            output: "z00",
+        }
+    );
+    assert!(LogicGate::try_from("x00 NAND y00 -> z00").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parse_and_solve_example() -> Result<()> {
+    let example = include_str!("../../examples/day-24/a.txt");
+
+    let input = parse_input_into_values_and_logic_gates(trim_newlines(example))?;
+
+    assert_eq!(input.1.len(), 3);
+    assert_eq!(part_1(&input)?, 4);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, ParseError, Solution, parse_section};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (Keys, Locks);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_keys_and_locks(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(Answer::NotApplicable)
+    }
+}
+
+pub fn part_1((keys, locks): &(Keys, Locks)) -> Result<usize> {
+    Ok(keys
+        .iter()
+        .map(|key| {
+            locks
+                .iter()
+                .filter(|lock| (0..5).all(|index| lock[index] + key[index] <= 5))
+                .count()
+        })
+        .sum())
+}
+
+pub type Heights = [u8; 5];
+pub type Keys = Vec<Heights>;
+pub type Locks = Vec<Heights>;
+
+pub fn parse_input_into_keys_and_locks(input: &str) -> Result<(Keys, Locks)> {
+    let schematics = input.split_terminator("\n\n").collect::<Vec<_>>();
+
+    let mut keys = Vec::new();
+    let mut locks = Vec::new();
+    for schematic in schematics {
+        parse_section(input, schematic, |schematic| {
+            parse_schematic_into_key_or_lock(schematic, &mut keys, &mut locks)
+        })?;
+    }
+
+    Ok((keys, locks))
+}
+
+fn parse_schematic_into_key_or_lock(
+    schematic: &str,
+    keys: &mut Keys,
+    locks: &mut Locks,
+) -> Result<()> {
+    let lines = schematic.lines().collect::<Vec<_>>();
+    if lines.len() != 7 || !(schematic.starts_with("#") || schematic.starts_with(".")) {
+        return Err(anyhow!("Invalid schematic, expected 7 lines of # or ."));
+    }
+
+    let mut heights: Heights = Default::default();
+    for line in lines.into_iter().skip(1).take(5) {
+        if line.len() != 5 {
+            return Err(ParseError::at(schematic, line, "Invalid line, expected 5 cells").into());
+        }
+
+        for (index, byte) in line.bytes().enumerate() {
+            if byte == b'#' {
+                heights[index] += 1;
+            }
+        }
+    }
+
+    if schematic.starts_with("#") {
+        locks.push(heights);
+    } else {
+        keys.push(heights);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(25, "example")?.check(&mut Solver, Part::Part1)
+    }
+}
@@ -0,0 +1,63 @@
+use anyhow::Result;
+use regex::Regex;
+
+use cli::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(input: &str) -> Result<u32> {
+    Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")?
+        .captures_iter(input)
+        .map(|capture| {
+            let (_, [x, y]) = capture.extract();
+
+            Ok(x.parse::<u32>()? * y.parse::<u32>()?)
+        })
+        .sum()
+}
+
+pub fn part_2(input: &str) -> Result<u32> {
+    part_1(&remove_disabled_sections(input)?)
+}
+
+/// Strips out sections marked by `don't()` and `do()`.
+///
+/// Does not handle any dangling `don't()` at the end.
+fn remove_disabled_sections(input: &str) -> Result<String> {
+    Ok(String::from(
+        Regex::new(r"(?s)don't\(\).*?do\(\)")?.replace_all(input, ""),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(3, "part-1")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(3, "part-2")?.check(&mut Solver, Part::Part2)
+    }
+}
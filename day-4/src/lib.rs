@@ -0,0 +1,74 @@
+use anyhow::Result;
+
+use cli::{Answer, Solution};
+use grid::{Direction, Grid};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Grid<u8>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Grid::parse(input, Ok)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(count_xmas(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(count_x_mas(input).into())
+    }
+}
+
+pub fn count_xmas(grid: &Grid<u8>) -> usize {
+    grid.positions(|&byte| byte == b'X')
+        .map(|coord| {
+            Direction::ALL_8
+                .into_iter()
+                .filter(|direction| {
+                    grid.ray(coord, direction.delta())
+                        .take(4)
+                        .map(|coord| grid[coord])
+                        .eq(*b"XMAS")
+                })
+                .count()
+        })
+        .sum()
+}
+
+pub fn count_x_mas(grid: &Grid<u8>) -> usize {
+    grid.positions(|&byte| byte == b'A')
+        .filter(|&coord| {
+            let corner = |direction: Direction| {
+                grid.step(coord, direction.delta())
+                    .map(|corner| grid[corner])
+            };
+            let is_mas = |direction: Direction| {
+                matches!(
+                    (corner(direction), corner(direction.reverse())),
+                    (Some(b'M'), Some(b'S')) | (Some(b'S'), Some(b'M'))
+                )
+            };
+
+            is_mas(Direction::NW) && is_mas(Direction::NE)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(4, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(4, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution, parse_fragment, parse_lines, parse_section};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (HashSet<Rule>, Vec<Update>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_rules_and_updates(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1((rules, updates): &(HashSet<Rule>, Vec<Update>)) -> Result<u32> {
+    Ok(updates
+        .iter()
+        .filter(|update| in_order(update, rules))
+        .map(middle_page_number)
+        .sum())
+}
+
+pub fn part_2((rules, updates): &(HashSet<Rule>, Vec<Update>)) -> Result<u32> {
+    Ok(updates
+        .iter()
+        .filter(|update| !in_order(update, rules))
+        .map(|update| rearrange(update, rules))
+        .map(|update| middle_page_number(&update))
+        .sum())
+}
+
+pub type Rule = (u32, u32);
+pub type Update = Vec<u32>;
+
+pub fn parse_input_into_rules_and_updates(input: &str) -> Result<(HashSet<Rule>, Vec<Update>)> {
+    let Some((rules, updates)) = input.split_once("\n\n") else {
+        return Err(anyhow!("Cannot split input into rules and updates"));
+    };
+
+    let rules = parse_section(input, rules, |rules| {
+        parse_lines(rules, |line| {
+            let Some((before, after)) = line.split_once("|") else {
+                return Err(anyhow!("Cannot split input into before and after"));
+            };
+
+            Ok((parse_fragment(line, before)?, parse_fragment(line, after)?))
+        })
+    })?;
+
+    let updates = parse_section(input, updates, |updates| {
+        parse_lines(updates, |line| {
+            line.split_terminator(",")
+                .map(|page| parse_fragment(line, page))
+                .collect()
+        })
+    })?;
+
+    Ok((rules.into_iter().collect(), updates))
+}
+
+fn in_order(update: &Update, rules: &HashSet<Rule>) -> bool {
+    update.iter().enumerate().all(|(index, page)| {
+        update[index + 1..]
+            .iter()
+            .all(|page_after| rules.contains(&(*page, *page_after)))
+    })
+}
+
+fn middle_page_number(update: &Update) -> u32 {
+    update[update.len() / 2]
+}
+
+fn rearrange(update: &Update, rules: &HashSet<Rule>) -> Update {
+    let mut rearranged = update.clone();
+    rearranged.sort_by_key(|page| {
+        update
+            .iter()
+            .filter(|&other_page| rules.contains(&(*page, *other_page)))
+            .count()
+    });
+    rearranged.reverse();
+
+    rearranged
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(5, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(5, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution};
+use grid::{Coord, Direction, Grid};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = (Grid<u8>, Guard);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_grid_and_guard(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1((grid, guard): &(Grid<u8>, Guard)) -> Result<usize> {
+    let footprints = footprints(*guard, grid);
+
+    Ok(footprints.len())
+}
+
+pub fn part_2((grid, guard): &(Grid<u8>, Guard)) -> Result<u32> {
+    let mut loop_obstacles = 0;
+
+    // The guard is diverted only if we place an obstacle on the original path.
+    for coord in footprints(*guard, grid) {
+        if guard.pos == coord {
+            continue;
+        }
+
+        let mut test_grid = grid.clone();
+        test_grid[coord] = b'#';
+        if is_loop(*guard, test_grid) {
+            loop_obstacles += 1;
+        }
+    }
+
+    Ok(loop_obstacles)
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Guard {
+    pub pos: Coord,
+    pub facing: Direction,
+}
+
+impl Guard {
+    /// Turns right at an obstacle, or else steps forward. Gets [None] when leaving the grid.
+    pub fn proceed(mut self, grid: &Grid<u8>) -> Option<Self> {
+        let next = grid.step(self.pos, self.facing.delta())?;
+
+        if grid[next] == b'#' {
+            self.facing = self.facing.turn_right();
+        } else {
+            self.pos = next;
+        }
+
+        Some(self)
+    }
+}
+
+pub fn parse_input_into_grid_and_guard(input: &str) -> Result<(Grid<u8>, Guard)> {
+    let grid = Grid::parse(input, Ok)?;
+    let pos = grid.find(&b'^').ok_or(anyhow!("Cannot find guard"))?;
+
+    Ok((
+        grid,
+        Guard {
+            pos,
+            facing: Direction::N,
+        },
+    ))
+}
+
+fn footprints(mut guard: Guard, grid: &Grid<u8>) -> HashSet<Coord> {
+    let mut footprints = HashSet::new();
+    footprints.insert(guard.pos);
+
+    while let Some(new_guard) = guard.proceed(grid) {
+        guard = new_guard;
+        footprints.insert(guard.pos);
+    }
+
+    footprints
+}
+
+fn is_loop(mut guard: Guard, grid: Grid<u8>) -> bool {
+    let mut footprints = HashSet::new();
+    footprints.insert(guard);
+
+    while let Some(new_guard) = guard.proceed(&grid) {
+        guard = new_guard;
+
+        if !footprints.insert(guard) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(6, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(6, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
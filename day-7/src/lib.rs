@@ -0,0 +1,137 @@
+use anyhow::{Result, anyhow};
+
+use cli::{Answer, Solution, parse_fragment, parse_lines};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Vec<Equation>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_input_into_equations(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(equations: &[Equation]) -> Result<u64> {
+    Ok(equations
+        .iter()
+        .filter(|equation| is_solvable(equation))
+        .map(|equation| equation.1)
+        .sum())
+}
+
+pub fn part_2(equations: &[Equation]) -> Result<u64> {
+    Ok(equations
+        .iter()
+        .filter(|equation| is_solvable_with_concat(equation))
+        .map(|equation| equation.1)
+        .sum())
+}
+
+/// (operands, test_value)
+pub type Equation = (Vec<u64>, u64);
+
+pub fn parse_input_into_equations(input: &str) -> Result<Vec<Equation>> {
+    parse_lines(input, |line| {
+        let Some((test_value, operands)) = line.split_once(": ") else {
+            return Err(anyhow!("Cannot split into test value and operands"));
+        };
+
+        let test_value = parse_fragment(line, test_value)?;
+        let operands = operands
+            .split_whitespace()
+            .map(|token| parse_fragment(line, token))
+            .collect::<Result<Vec<_>>>()?;
+        if operands.is_empty() {
+            return Err(anyhow!("Cannot find operands"));
+        }
+
+        Ok((operands, test_value))
+    })
+}
+
+fn is_solvable(equation: &Equation) -> bool {
+    check_is_solvable(equation.1, equation.0[0], &equation.0[1..])
+}
+
+fn check_is_solvable(target: u64, left_operand: u64, right_operands: &[u64]) -> bool {
+    if left_operand > target {
+        return false;
+    }
+
+    if right_operands.is_empty() {
+        return left_operand == target;
+    }
+
+    check_is_solvable(
+        target,
+        left_operand + right_operands[0],
+        &right_operands[1..],
+    ) || check_is_solvable(
+        target,
+        left_operand * right_operands[0],
+        &right_operands[1..],
+    )
+}
+
+fn is_solvable_with_concat(equation: &Equation) -> bool {
+    check_is_solvable_with_concat(equation.1, equation.0[0], &equation.0[1..])
+}
+
+fn check_is_solvable_with_concat(target: u64, left_operand: u64, right_operands: &[u64]) -> bool {
+    if left_operand > target {
+        return false;
+    }
+
+    if right_operands.is_empty() {
+        return left_operand == target;
+    }
+
+    check_is_solvable_with_concat(
+        target,
+        left_operand + right_operands[0],
+        &right_operands[1..],
+    ) || check_is_solvable_with_concat(
+        target,
+        left_operand * right_operands[0],
+        &right_operands[1..],
+    ) || check_is_solvable_with_concat(
+        target,
+        concat(left_operand, right_operands[0]),
+        &right_operands[1..],
+    )
+}
+
+/// Concatenates left_operand and right_operand into one number.
+///
+/// Both left_operand and right_operand must be > 0.
+fn concat(left_operand: u64, right_operand: u64) -> u64 {
+    let digits = right_operand.ilog10() + 1;
+
+    left_operand * 10u64.pow(digits) + right_operand
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(7, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(7, "example")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use cli::{Answer, Solution};
+use grid::{Coord, Delta, Grid};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input<'a> = Grid<u8>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Grid::parse(input, Ok)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn part_1(grid: &Grid<u8>) -> Result<usize> {
+    Ok(antennae(grid)
+        .values()
+        .flat_map(|antennae| antinodes(antennae, grid))
+        .collect::<HashSet<_>>()
+        .len())
+}
+
+pub fn part_2(grid: &Grid<u8>) -> Result<usize> {
+    Ok(antennae(grid)
+        .values()
+        .flat_map(|antennae| antinodes_with_harmonics(antennae, grid))
+        .collect::<HashSet<_>>()
+        .len())
+}
+
+/// Groups the antennae by frequency.
+fn antennae(grid: &Grid<u8>) -> HashMap<u8, Vec<Coord>> {
+    grid.iter()
+        .filter(|(_, byte)| **byte != b'.')
+        .map(|(coord, byte)| (*byte, coord))
+        .into_group_map()
+}
+
+fn antinodes(antennae: &[Coord], grid: &Grid<u8>) -> Vec<Coord> {
+    antennae
+        .iter()
+        .tuple_combinations()
+        .flat_map(|(&coord_1, &coord_2)| {
+            // Virtual order:
+            //   antinode 0: coord_1 + delta,
+            //   antenna 1:  coord_1,
+            //   antenna 2:  coord_2,
+            //   antinode 3: coord_2 - delta,
+
+            let delta = delta(coord_1, coord_2);
+
+            [
+                grid.step(coord_1, delta),
+                grid.step(coord_2, (-delta.0, -delta.1)),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+fn antinodes_with_harmonics(antennae: &[Coord], grid: &Grid<u8>) -> Vec<Coord> {
+    antennae
+        .iter()
+        .tuple_combinations()
+        .flat_map(|(&coord_1, &coord_2)| {
+            // Both rays include the antennae themselves.
+            let delta = delta(coord_1, coord_2);
+
+            grid.ray(coord_1, delta)
+                .chain(grid.ray(coord_2, (-delta.0, -delta.1)))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Gets the delta from coord_2 to coord_1.
+fn delta(coord_1: Coord, coord_2: Coord) -> Delta {
+    (
+        coord_1.0 as isize - coord_2.0 as isize,
+        coord_1.1 as isize - coord_2.1 as isize,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::{Part, load_example};
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        load_example!(8, "example")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        load_example!(8, "example")?.check(&mut Solver, Part::Part2)
+    }
+
+    #[test]
+    fn example_1_wide() -> Result<()> {
+        load_example!(8, "wide")?.check(&mut Solver, Part::Part1)
+    }
+
+    #[test]
+    fn example_2_wide() -> Result<()> {
+        load_example!(8, "wide")?.check(&mut Solver, Part::Part2)
+    }
+}
@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use crate::Delta;
+
+/// A compass direction on the grid, with north pointing up.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions, clockwise from north.
+    pub const ALL_4: [Self; 4] = [Self::N, Self::E, Self::S, Self::W];
+
+    /// The 8 orthogonal and diagonal directions, clockwise from north.
+    pub const ALL_8: [Self; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    /// Turns 90 degrees anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Gets the (row, col) delta of one step in the direction.
+    pub fn delta(self) -> Delta {
+        match self {
+            Self::N => (-1, 0),
+            Self::NE => (-1, 1),
+            Self::E => (0, 1),
+            Self::SE => (1, 1),
+            Self::S => (1, 0),
+            Self::SW => (1, -1),
+            Self::W => (0, -1),
+            Self::NW => (-1, -1),
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::N | Self::S)
+    }
+
+    /// Rotates clockwise by 45 degrees at a time.
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL_8[(self as usize + eighths) % 8]
+    }
+}
+
+/// Parses an arrow (`^`, `>`, `v`, `<`) or a compass letter (`N`, `E`, `S`, `W`).
+impl TryFrom<u8> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'^' | b'N' => Ok(Self::N),
+            b'>' | b'E' => Ok(Self::E),
+            b'v' | b'S' => Ok(Self::S),
+            b'<' | b'W' => Ok(Self::W),
+            _ => Err(anyhow!("Invalid direction: {}", value as char)),
+        }
+    }
+}
+
+/// Parses an arrow or a compass point, including the diagonals (`NE`, `SE`, `SW`, `NW`).
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Self::NE),
+            "SE" => Ok(Self::SE),
+            "SW" => Ok(Self::SW),
+            "NW" => Ok(Self::NW),
+            _ => match s.as_bytes() {
+                [byte] => Self::try_from(*byte),
+                _ => Err(anyhow!("Invalid direction: {}", s)),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn turn_directions() {
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::W.turn_right(), Direction::N);
+        assert_eq!(Direction::NE.turn_left(), Direction::NW);
+        assert_eq!(Direction::SE.reverse(), Direction::NW);
+        assert!(
+            Direction::ALL_8
+                .into_iter()
+                .all(|direction| direction.turn_left().turn_right() == direction)
+        );
+    }
+
+    #[test]
+    fn parse_directions() -> Result<()> {
+        assert_eq!(
+            b"^>v<".map(Direction::try_from).map(Result::ok),
+            Direction::ALL_4.map(Some)
+        );
+        assert_eq!(
+            "N E S W"
+                .split(" ")
+                .map(Direction::from_str)
+                .collect::<Result<Vec<_>>>()?,
+            Direction::ALL_4
+        );
+        assert_eq!("SW".parse::<Direction>()?, Direction::SW);
+        assert!(Direction::try_from(b'x').is_err());
+        assert!("NNE".parse::<Direction>().is_err());
+
+        Ok(())
+    }
+}
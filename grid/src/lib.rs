@@ -0,0 +1,304 @@
+use std::{
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use anyhow::{Result, anyhow};
+
+use cli::ParseError;
+
+pub use direction::Direction;
+
+mod direction;
+
+/// (row, col)
+pub type Coord = (usize, usize);
+
+/// (row, col)
+pub type Delta = (isize, isize);
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, one row per line, mapping each byte to a cell.
+    ///
+    /// Errors are located at the row or cell at fault.
+    pub fn parse(s: &str, mut f: impl FnMut(u8) -> Result<T>) -> Result<Self> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let width = lines.first().map_or(0, |line| line.len());
+        let height = lines.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for line in lines {
+            if line.len() != width {
+                return Err(ParseError::at(
+                    s,
+                    line,
+                    format!("Invalid grid row, expected {width} cells"),
+                )
+                .into());
+            }
+
+            for (col, byte) in line.bytes().enumerate() {
+                let cell = f(byte).map_err(|error| {
+                    ParseError::at(s, line.get(col..col + 1).unwrap_or(line), error)
+                })?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a grid from its cells, row by row.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Result<Self> {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        if width * height != cells.len() {
+            return Err(anyhow!("Invalid grid width: {}", width));
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks whether the coord is inside the grid.
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.0 < self.height && coord.1 < self.width
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        if self.contains(coord) {
+            Some(&self.cells[coord.0 * self.width + coord.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            Some(&mut self.cells[coord.0 * self.width + coord.1])
+        } else {
+            None
+        }
+    }
+
+    /// Swaps the cells at 2 coords.
+    pub fn swap(&mut self, coord: Coord, other: Coord) {
+        assert!(self.contains(coord) && self.contains(other));
+
+        self.cells.swap(
+            coord.0 * self.width + coord.1,
+            other.0 * self.width + other.1,
+        );
+    }
+
+    /// Moves one step from the coord, if that stays inside the grid.
+    pub fn step(&self, coord: Coord, delta: Delta) -> Option<Coord> {
+        let row = coord.0.checked_add_signed(delta.0)?;
+        let col = coord.1.checked_add_signed(delta.1)?;
+
+        self.contains((row, col)).then_some((row, col))
+    }
+
+    /// Gets the 4 orthogonal neighbours of the coord that are inside the grid.
+    pub fn neighbours_4(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        Direction::ALL_4
+            .into_iter()
+            .filter_map(move |direction| self.step(coord, direction.delta()))
+    }
+
+    /// Gets the 8 orthogonal and diagonal neighbours of the coord that are inside the grid.
+    pub fn neighbours_8(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        Direction::ALL_8
+            .into_iter()
+            .filter_map(move |direction| self.step(coord, direction.delta()))
+    }
+
+    /// Walks from the coord (inclusive) in steps of delta, until the edge of the grid.
+    pub fn ray(&self, coord: Coord, delta: Delta) -> impl Iterator<Item = Coord> {
+        iter::successors(self.contains(coord).then_some(coord), move |&coord| {
+            self.step(coord, delta)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.ray((row, 0), Direction::E.delta())
+            .map(|coord| &self[coord])
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.ray((0, col), Direction::S.delta())
+            .map(|coord| &self[coord])
+    }
+
+    /// Gets the cells from the coord down and to the right.
+    pub fn diagonal(&self, coord: Coord) -> impl Iterator<Item = &T> {
+        self.ray(coord, Direction::SE.delta())
+            .map(|coord| &self[coord])
+    }
+
+    /// Gets the cells from the coord down and to the left.
+    pub fn anti_diagonal(&self, coord: Coord) -> impl Iterator<Item = &T> {
+        self.ray(coord, Direction::SW.delta())
+            .map(|coord| &self[coord])
+    }
+
+    /// Gets every coord, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + use<T> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Gets every cell with its coord, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(&self.cells)
+    }
+
+    /// Gets the coords of the cells that match the predicate, row by row.
+    pub fn positions(&self, mut predicate: impl FnMut(&T) -> bool) -> impl Iterator<Item = Coord> {
+        self.iter()
+            .filter_map(move |(coord, cell)| predicate(cell).then_some(coord))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to the value.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Finds the first cell with the value, such as the start of a maze.
+    pub fn find(&self, value: &T) -> Option<Coord> {
+        self.positions(|cell| cell == value).next()
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("Coord out of bounds: {coord:?}"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("Coord out of bounds: {coord:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "S.#\n.#E";
+
+    #[test]
+    fn parse_rectangular_grid() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, |byte| Ok(byte == b'#'))?;
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(0, 2)]);
+        assert!(!grid[(1, 2)]);
+        assert_eq!(grid.get((2, 0)), None);
+        assert!(Grid::parse("..\n.", Ok).is_err());
+
+        let error = Grid::parse("..\n.x", |byte| match byte {
+            b'.' => Ok(()),
+            _ => Err(anyhow!("Invalid cell")),
+        })
+        .unwrap_err()
+        .downcast::<ParseError>()?;
+        assert_eq!((error.line(), error.column()), (2, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_special_cells() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+
+        assert_eq!(grid.find(&b'S'), Some((0, 0)));
+        assert_eq!(grid.find(&b'E'), Some((1, 2)));
+        assert_eq!(grid.find(&b'@'), None);
+        assert_eq!(
+            grid.positions(|&byte| byte == b'.').collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn neighbours_inside_grid() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours_8((0, 1)).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+        assert_eq!(grid.neighbours_4((1, 2)).count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lines_through_grid() -> Result<()> {
+        let grid = Grid::parse(EXAMPLE, Ok)?;
+
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), b".#E");
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"#E");
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.diagonal((0, 0)).copied().collect::<Vec<_>>(), b"S#");
+        assert_eq!(
+            grid.anti_diagonal((0, 2)).copied().collect::<Vec<_>>(),
+            b"##"
+        );
+        assert_eq!(grid.ray((1, 2), (0, -1)).count(), 3);
+
+        Ok(())
+    }
+}